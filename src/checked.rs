@@ -0,0 +1,136 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fallible counterparts of the operators and `Float` methods.
+//!
+//! Each `checked_*` method computes the same value as the operator or method it is named after,
+//! but returns an `InvalidFloat` instead of panicking if the result is invalid.
+//! The `Checked*` traits of `num_traits` are implemented on top of them, returning `Option`.
+
+use crate::{checkers::Join, FloatChecker, InvalidFloat, NoisyFloat, Operation};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub, Float, NumCast,
+};
+
+macro_rules! checked_methods {
+    ($(fn $name:ident => $method:ident($($arg:ident : $argtp:tt),*) $(as $symbol:literal)?;)+) => {
+        $(
+            #[doc = concat!(
+                "Computes `", stringify!($method), "`, returning an `InvalidFloat` ",
+                "instead of panicking if the result is invalid."
+            )]
+            #[inline]
+            pub fn $name(self $(, $arg: $argtp)*) -> Result<Self, InvalidFloat<F>> {
+                Self::try_new_checked(self.value.$method($(checked_methods!(@raw $arg: $argtp)),*))
+                    .map_err(|error| {
+                        error.with_operation(Operation::new(
+                            checked_methods!(@name $method $($symbol)?),
+                            &[self.value $(, checked_methods!(@operand $arg: $argtp))*],
+                        ))
+                    })
+            }
+        )+
+    };
+    (@raw $arg:ident : Self) => { $arg.value };
+    (@raw $arg:ident : $argtp:tt) => { $arg };
+    (@operand $arg:ident : Self) => { $arg.value };
+    (@operand $arg:ident : $argtp:tt) => { <F as NumCast>::from($arg).unwrap_or_else(F::nan) };
+    (@name $method:ident $symbol:literal) => { $symbol };
+    (@name $method:ident) => { stringify!($method) };
+}
+
+impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
+    checked_methods! {
+        fn checked_add => add(rhs: Self) as "+";
+        fn checked_sub => sub(rhs: Self) as "-";
+        fn checked_mul => mul(rhs: Self) as "*";
+        fn checked_div => div(rhs: Self) as "/";
+        fn checked_rem => rem(rhs: Self) as "%";
+        fn checked_neg => neg() as "-";
+        fn checked_floor => floor();
+        fn checked_ceil => ceil();
+        fn checked_round => round();
+        fn checked_trunc => trunc();
+        fn checked_fract => fract();
+        fn checked_abs => abs();
+        fn checked_signum => signum();
+        fn checked_mul_add => mul_add(a: Self, b: Self);
+        fn checked_recip => recip();
+        fn checked_powi => powi(n: i32);
+        fn checked_powf => powf(n: Self);
+        fn checked_sqrt => sqrt();
+        fn checked_exp => exp();
+        fn checked_exp2 => exp2();
+        fn checked_ln => ln();
+        fn checked_log => log(base: Self);
+        fn checked_log2 => log2();
+        fn checked_log10 => log10();
+        fn checked_abs_sub => abs_sub(other: Self);
+        fn checked_cbrt => cbrt();
+        fn checked_hypot => hypot(other: Self);
+        fn checked_sin => sin();
+        fn checked_cos => cos();
+        fn checked_tan => tan();
+        fn checked_asin => asin();
+        fn checked_acos => acos();
+        fn checked_atan => atan();
+        fn checked_atan2 => atan2(other: Self);
+        fn checked_exp_m1 => exp_m1();
+        fn checked_ln_1p => ln_1p();
+        fn checked_sinh => sinh();
+        fn checked_cosh => cosh();
+        fn checked_tanh => tanh();
+        fn checked_asinh => asinh();
+        fn checked_acosh => acosh();
+        fn checked_atanh => atanh();
+        fn checked_to_degrees => to_degrees();
+        fn checked_to_radians => to_radians();
+    }
+
+    /// Computes `sin_cos`, returning an `InvalidFloat` instead of panicking
+    /// if either result is invalid.
+    #[inline]
+    pub fn checked_sin_cos(self) -> Result<(Self, Self), InvalidFloat<F>> {
+        let (sin, cos) = self.value.sin_cos();
+        let with_operation =
+            |error: InvalidFloat<F>| error.with_operation(Operation::new("sin_cos", &[self.value]));
+        Ok((
+            Self::try_new_checked(sin).map_err(with_operation)?,
+            Self::try_new_checked(cos).map_err(with_operation)?,
+        ))
+    }
+}
+
+macro_rules! checked_trait_impl {
+    ($trid:ident, $fnid:ident) => {
+        impl<F: Float, C: FloatChecker<F> + Join<C, Output = C>> $trid for NoisyFloat<F, C> {
+            #[inline]
+            fn $fnid(&self, v: &Self) -> Option<Self> {
+                NoisyFloat::$fnid(*self, *v).ok()
+            }
+        }
+    };
+}
+checked_trait_impl!(CheckedAdd, checked_add);
+checked_trait_impl!(CheckedSub, checked_sub);
+checked_trait_impl!(CheckedMul, checked_mul);
+checked_trait_impl!(CheckedDiv, checked_div);
+checked_trait_impl!(CheckedRem, checked_rem);
+
+impl<F: Float, C: FloatChecker<F>> CheckedNeg for NoisyFloat<F, C> {
+    #[inline]
+    fn checked_neg(&self) -> Option<Self> {
+        NoisyFloat::checked_neg(*self).ok()
+    }
+}
@@ -14,23 +14,97 @@
 
 //! Standard implementations of `FloatChecker`.
 
-use core::convert::TryFrom;
+use core::{any, convert::TryFrom, marker::PhantomData, mem, num::FpCategory};
 
-use crate::{FloatChecker, NoisyFloat};
+use crate::{
+    flags::{self, Flags},
+    sampling, violation, FloatChecker, InvalidFloat, InvalidReason, NoisyFloat, Operation,
+};
 use num_traits::Float;
 
+/// Returns `C::check(value)`, counting the check and any violation
+/// with the `profiling` feature.
+#[track_caller]
+#[inline]
+fn checked<F: Float, C: FloatChecker<F>>(value: F) -> bool {
+    let valid = C::check(value);
+    #[cfg(feature = "profiling")]
+    {
+        crate::stats::record::<F, C>(crate::stats::Event::Check);
+        if !valid {
+            crate::stats::record::<F, C>(crate::stats::Event::Violation);
+        }
+    }
+    valid
+}
+
+/// Implements `assert`, `assert_or_replace` and `assert_operation`,
+/// reporting *invalid* values to the violation handler if `$enabled` holds.
+macro_rules! report_asserts {
+    ($enabled:expr) => {
+        #[track_caller]
+        #[inline]
+        fn assert(value: F) {
+            Self::assert_or_replace(value);
+        }
+
+        #[track_caller]
+        #[inline]
+        fn assert_or_replace(value: F) -> F {
+            if $enabled && !checked::<F, Self>(value) {
+                violation::report::<F, Self>(value)
+            } else {
+                value
+            }
+        }
+
+        #[track_caller]
+        #[inline]
+        fn assert_operation(value: F, operation: Operation<F>) -> F {
+            if $enabled && !checked::<F, Self>(value) {
+                violation::report_operation::<F, Self>(value, operation)
+            } else {
+                value
+            }
+        }
+    };
+}
+
+/// Implements `assert` and `assert_operation` like `debug_assert!`,
+/// or like `assert!` when the `strict` feature is enabled,
+/// names the checker after the standard types, such as `"R64"`,
+/// and takes the capabilities from the `admits!` table.
+macro_rules! standard_asserts {
+    ($prefix:literal) => {
+        report_asserts!(cfg!(any(debug_assertions, feature = "strict")));
+
+        const CAPABILITIES: Capabilities = Self::ADMITS;
+
+        #[inline]
+        fn name() -> &'static str {
+            if mem::size_of::<F>() == mem::size_of::<f32>() {
+                concat!($prefix, "32")
+            } else {
+                concat!($prefix, "64")
+            }
+        }
+    };
+}
+
 /// A `FloatChecker` that considers all values valid except NaN.
 ///
 /// This checks that the value is a "number", i.e. it is not "not-a-number".
 ///
-/// The `assert` method is implemented using `debug_assert!`.
+/// The `assert` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature.
 pub struct NumChecker;
 
 impl<F: Float> FloatChecker<F> for NumChecker {
-    #[track_caller]
+    standard_asserts!("N");
+
     #[inline]
-    fn assert(value: F) {
-        debug_assert!(Self::check(value), "unexpected NaN");
+    fn message(_value: F) -> &'static str {
+        "unexpected NaN"
     }
 
     #[inline]
@@ -41,33 +115,1550 @@ impl<F: Float> FloatChecker<F> for NumChecker {
 
 /// A `FloatChecker` that considers all values valid except NaN and +/- Infinity.
 ///
-/// The `assert` method is implemented using `debug_assert!`.
+/// The `assert` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature.
 pub struct FiniteChecker;
 
 impl<F: Float> FloatChecker<F> for FiniteChecker {
+    standard_asserts!("R");
+
+    #[inline]
+    fn message(_value: F) -> &'static str {
+        "unexpected NaN or infinity"
+    }
+
+    #[inline]
+    fn check(value: F) -> bool {
+        value.is_finite()
+    }
+}
+
+macro_rules! sign_checker {
+    ($(#[$attr:meta])* $name:ident, $prefix:literal, |$value:ident| $check:expr, $msg:expr) => {
+        $(#[$attr])*
+        pub struct $name;
+
+        impl<F: Float> FloatChecker<F> for $name {
+            standard_asserts!($prefix);
+
+            #[inline]
+            fn message(_value: F) -> &'static str {
+                $msg
+            }
+
+            #[inline]
+            fn check($value: F) -> bool {
+                $check
+            }
+        }
+    };
+}
+
+sign_checker!(
+    /// A `FloatChecker` that considers all values greater than zero valid,
+    /// including +Infinity.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    PositiveChecker,
+    "PX",
+    |value| value > F::zero(),
+    "unexpected NaN or non-positive value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers all finite values greater than zero valid.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    PositiveFiniteChecker,
+    "P",
+    |value| value > F::zero() && value.is_finite(),
+    "unexpected NaN, infinity or non-positive value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers zero and all values greater than zero valid,
+    /// including +Infinity.
+    ///
+    /// Both +0.0 and -0.0 are considered valid.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NonNegativeChecker,
+    "NNX",
+    |value| value >= F::zero(),
+    "unexpected NaN or negative value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers zero and all finite values greater than zero valid.
+    ///
+    /// Both +0.0 and -0.0 are considered valid.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NonNegativeFiniteChecker,
+    "NN",
+    |value| value >= F::zero() && value.is_finite(),
+    "unexpected NaN, infinity or negative value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers all values less than zero valid,
+    /// including -Infinity.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NegativeChecker,
+    "NGX",
+    |value| value < F::zero(),
+    "unexpected NaN or non-negative value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers all finite values less than zero valid.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NegativeFiniteChecker,
+    "NG",
+    |value| value < F::zero() && value.is_finite(),
+    "unexpected NaN, infinity or non-negative value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers zero and all values less than zero valid,
+    /// including -Infinity.
+    ///
+    /// Both +0.0 and -0.0 are considered valid.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NonPositiveChecker,
+    "NPX",
+    |value| value <= F::zero(),
+    "unexpected NaN or positive value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers zero and all finite values less than zero valid.
+    ///
+    /// Both +0.0 and -0.0 are considered valid.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NonPositiveFiniteChecker,
+    "NP",
+    |value| value <= F::zero() && value.is_finite(),
+    "unexpected NaN, infinity or positive value"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers all values except NaN and zero valid,
+    /// including +/- Infinity.
+    ///
+    /// Both +0.0 and -0.0 are considered invalid.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NonZeroChecker,
+    "NZX",
+    |value| !value.is_nan() && !value.is_zero(),
+    "unexpected NaN or zero"
+);
+
+sign_checker!(
+    /// A `FloatChecker` that considers all finite values except zero valid.
+    ///
+    /// Both +0.0 and -0.0 are considered invalid.
+    /// Dividing a finite `NoisyFloat` by a value with this checker
+    /// can never produce NaN or infinity through a division by zero.
+    ///
+    /// The `assert` method is implemented using `debug_assert!`,
+    /// or `assert!` with the `strict` feature.
+    NonZeroFiniteChecker,
+    "NZ",
+    |value| value.is_finite() && !value.is_zero(),
+    "unexpected NaN, infinity or zero"
+);
+
+/// A `FloatChecker` that considers all finite values valid except subnormal numbers.
+///
+/// Both +0.0 and -0.0 are considered valid.
+/// Subnormal numbers are very slow to compute with on many processors,
+/// so this checker can be used to find the place where one is produced.
+/// `NoisyFloat::new_flush_to_zero` can be used to construct values from inputs
+/// that may be subnormal.
+///
+/// The `assert` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature.
+pub struct NormalChecker;
+
+impl<F: Float> FloatChecker<F> for NormalChecker {
+    standard_asserts!("NR");
+
+    #[inline]
+    fn check(value: F) -> bool {
+        value.is_normal() || value.is_zero()
+    }
+
+    #[inline]
+    fn message(_value: F) -> &'static str {
+        "unexpected NaN, infinity or subnormal value"
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        if Self::check(value) {
+            Ok(())
+        } else if value.is_subnormal() {
+            Err(InvalidReason::Subnormal)
+        } else {
+            Err(InvalidReason::classify(value))
+        }
+    }
+}
+
+/// The constants that a `FloatChecker` considers valid, as reported by
+/// `FloatChecker::CAPABILITIES`.
+///
+/// The `Zero`, `One` and `Bounded` traits are implemented for every `NoisyFloat`,
+/// but using a constructor whose constant the checker does not admit fails to compile
+/// when the function using it is monomorphized, rather than panicking at runtime:
+///
+/// ```compile_fail
+/// use noisy_float::prelude::*;
+/// use num_traits::Zero;
+///
+/// let zero = P64::zero();
+/// ```
+///
+/// The constructors of the `Float` trait, such as `Float::nan` and `Float::infinity`,
+/// are checked at runtime instead, so that generic code mentioning them
+/// in a branch that is never taken still compiles for every checker:
+///
+/// ```
+/// use noisy_float::prelude::*;
+///
+/// fn safe_div<T: Float>(a: T, b: T) -> T {
+///     if b == T::zero() {
+///         T::nan()
+///     } else {
+///         a / b
+///     }
+/// }
+///
+/// assert_eq!(safe_div(r64(3.0), r64(2.0)), 1.5);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Capabilities {
+    /// Both +0.0 and -0.0 are *valid*.
+    pub zero: bool,
+    /// One is *valid*.
+    pub one: bool,
+    /// All positive normal values are *valid*, up to and including `max_value`,
+    /// as are `min_positive_value` and `epsilon`.
+    pub positive: bool,
+    /// All negative normal values are *valid*, down to and including `min_value`.
+    pub negative: bool,
+    /// The infinities are *valid* on each side of zero that is admitted.
+    pub infinity: bool,
+}
+
+impl Capabilities {
+    /// Admits no constants at all.
+    pub const NONE: Self = Capabilities {
+        zero: false,
+        one: false,
+        positive: false,
+        negative: false,
+        infinity: false,
+    };
+
+    /// Admits all constants except NaN, leaving them to the runtime check.
+    /// This is the default for custom checkers.
+    pub const ALL: Self = Capabilities {
+        zero: true,
+        one: true,
+        positive: true,
+        negative: true,
+        infinity: true,
+    };
+
+    /// Returns the constants admitted by either `self` or `other`.
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Capabilities {
+            zero: self.zero || other.zero,
+            one: self.one || other.one,
+            positive: self.positive || other.positive,
+            negative: self.negative || other.negative,
+            infinity: self.infinity || other.infinity,
+        }
+    }
+
+    /// Returns the constants admitted by both `self` and `other`.
+    #[inline]
+    pub const fn intersection(self, other: Self) -> Self {
+        Capabilities {
+            zero: self.zero && other.zero,
+            one: self.one && other.one,
+            positive: self.positive && other.positive,
+            negative: self.negative && other.negative,
+            infinity: self.infinity && other.infinity,
+        }
+    }
+}
+
+/// Compile-time assertions that the checker `C` admits a constant.
+///
+/// Evaluating one of these constants fails when a function using it
+/// is monomorphized for a checker that does not admit the constant.
+pub(crate) struct Admits<F, C>(PhantomData<(F, C)>);
+
+impl<F: Float, C: FloatChecker<F>> Admits<F, C> {
+    pub(crate) const ZERO: () = assert!(C::CAPABILITIES.zero, "the checker does not admit zero");
+    pub(crate) const ONE: () = assert!(C::CAPABILITIES.one, "the checker does not admit one");
+    pub(crate) const POSITIVE: () = assert!(
+        C::CAPABILITIES.positive,
+        "the checker does not admit positive values"
+    );
+    pub(crate) const NEGATIVE: () = assert!(
+        C::CAPABILITIES.negative,
+        "the checker does not admit negative values"
+    );
+}
+
+/// Marker trait for checkers that consider both +0.0 and -0.0 valid.
+///
+/// Implementing it enables `Default` and `NoisyFloat::neg_zero`,
+/// so that constructing a zero of a type that rejects it fails to compile:
+///
+/// ```compile_fail
+/// use noisy_float::prelude::*;
+///
+/// let zero = P64::default();
+/// ```
+///
+/// `Zero::zero` is checked through `FloatChecker::CAPABILITIES` instead.
+pub trait AdmitsZero {}
+
+/// Marker trait for checkers that consider one valid.
+pub trait AdmitsOne {}
+
+/// Marker trait for checkers that consider all positive normal values valid,
+/// up to and including `max_value`.
+pub trait AdmitsPositive: AdmitsOne {}
+
+/// Marker trait for checkers that consider all negative normal values valid,
+/// down to and including `min_value`.
+pub trait AdmitsNegative {}
+
+/// Marker trait for checkers that consider the infinities valid
+/// on each side of zero that they admit.
+///
+/// Implementing it together with `AdmitsPositive` enables `NoisyFloat::infinity`,
+/// and together with `AdmitsNegative` it enables `NoisyFloat::neg_infinity`.
+pub trait AdmitsInfinity {}
+
+// Implements the markers, and the matching `Capabilities` as `$checker::ADMITS`.
+macro_rules! admits {
+    ($checker:ty: $($marker:ident),+) => {
+        $(impl $marker for $checker {})+
+
+        impl $checker {
+            const ADMITS: Capabilities = Capabilities::NONE$(.union(admits!(@$marker)))+;
+        }
+    };
+    (@AdmitsZero) => { Capabilities { zero: true, ..Capabilities::NONE } };
+    (@AdmitsOne) => { Capabilities { one: true, ..Capabilities::NONE } };
+    (@AdmitsPositive) => { Capabilities { positive: true, ..Capabilities::NONE } };
+    (@AdmitsNegative) => { Capabilities { negative: true, ..Capabilities::NONE } };
+    (@AdmitsInfinity) => { Capabilities { infinity: true, ..Capabilities::NONE } };
+}
+
+admits!(NumChecker: AdmitsZero, AdmitsOne, AdmitsPositive, AdmitsNegative, AdmitsInfinity);
+admits!(FiniteChecker: AdmitsZero, AdmitsOne, AdmitsPositive, AdmitsNegative);
+admits!(PositiveChecker: AdmitsOne, AdmitsPositive, AdmitsInfinity);
+admits!(PositiveFiniteChecker: AdmitsOne, AdmitsPositive);
+admits!(NonNegativeChecker: AdmitsZero, AdmitsOne, AdmitsPositive, AdmitsInfinity);
+admits!(NonNegativeFiniteChecker: AdmitsZero, AdmitsOne, AdmitsPositive);
+admits!(NegativeChecker: AdmitsNegative, AdmitsInfinity);
+admits!(NegativeFiniteChecker: AdmitsNegative);
+admits!(NonPositiveChecker: AdmitsZero, AdmitsNegative, AdmitsInfinity);
+admits!(NonPositiveFiniteChecker: AdmitsZero, AdmitsNegative);
+admits!(NonZeroChecker: AdmitsOne, AdmitsPositive, AdmitsNegative, AdmitsInfinity);
+admits!(NonZeroFiniteChecker: AdmitsOne, AdmitsPositive, AdmitsNegative);
+admits!(NormalChecker: AdmitsZero, AdmitsOne, AdmitsPositive, AdmitsNegative);
+admits!(UnitChecker: AdmitsZero, AdmitsOne);
+
+/// Trait for checkers that can replace an *invalid* value other than NaN
+/// by the nearest *valid* value.
+///
+/// Implementing it enables `NoisyFloat::new_clamped` and the arithmetic of `Saturating`.
+/// Infinities are clamped to `max_value` or `min_value`, and a value that is too close
+/// to zero is clamped to zero or, if zero is invalid, to the smallest subnormal number
+/// of the same sign.
+pub trait Clamp<F>: FloatChecker<F> {
+    /// Returns the *valid* value nearest to `value`, or `value` itself if it is *valid*.
+    ///
+    /// Is never called with NaN.
+    fn clamp(value: F) -> F;
+}
+
+// The smallest positive subnormal number, `2^-1074` for `f64`.
+#[inline]
+fn min_subnormal<F: Float>() -> F {
+    F::min_positive_value() * F::epsilon()
+}
+
+#[inline]
+fn clamp_finite<F: Float>(value: F) -> F {
+    value.max(F::min_value()).min(F::max_value())
+}
+
+#[inline]
+fn clamp_non_zero<F: Float>(value: F) -> F {
+    if !value.is_zero() {
+        value
+    } else if value.is_sign_negative() {
+        -min_subnormal::<F>()
+    } else {
+        min_subnormal()
+    }
+}
+
+macro_rules! clamp {
+    ($($checker:ty: |$value:ident| $clamp:expr;)+) => {
+        $(
+            impl<F: Float> Clamp<F> for $checker {
+                #[inline]
+                fn clamp($value: F) -> F {
+                    $clamp
+                }
+            }
+        )+
+    };
+}
+
+clamp! {
+    NumChecker: |value| value;
+    FiniteChecker: |value| clamp_finite(value);
+    PositiveChecker: |value| value.max(min_subnormal());
+    PositiveFiniteChecker: |value| clamp_finite(value.max(min_subnormal()));
+    NonNegativeChecker: |value| if value < F::zero() { F::zero() } else { value };
+    NonNegativeFiniteChecker: |value| clamp_finite(value.max(F::zero()));
+    NegativeChecker: |value| value.min(-min_subnormal::<F>());
+    NegativeFiniteChecker: |value| clamp_finite(value.min(-min_subnormal::<F>()));
+    NonPositiveChecker: |value| if value > F::zero() { F::neg_zero() } else { value };
+    NonPositiveFiniteChecker: |value| clamp_finite(value.min(F::neg_zero()));
+    NonZeroChecker: |value| clamp_non_zero(value);
+    NonZeroFiniteChecker: |value| clamp_finite(clamp_non_zero(value));
+    NormalChecker: |value| if value.is_subnormal() { value * F::zero() } else { clamp_finite(value) };
+    UnitChecker: |value| value.max(F::zero()).min(F::one());
+}
+
+/// Marker trait for checkers whose valid values are all valid for the checker `C`.
+///
+/// Implementing it enables `NoisyFloat::widen`, which converts without checking,
+/// and `NoisyFloat::narrow`, which converts the other way with a check.
+/// Every checker is a subset of itself.
+/// The standard checkers additionally implement `From` and `TryFrom` along these relations.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{checkers::{FiniteChecker, Subset}, FloatChecker, NoisyFloat};
+///
+/// struct SmallChecker;
+///
+/// impl FloatChecker<f64> for SmallChecker {
+///     fn check(value: f64) -> bool {
+///         value.abs() < 1e-6
+///     }
+///
+///     fn assert(value: f64) {
+///         debug_assert!(Self::check(value), "unexpected NaN or large value");
+///     }
+/// }
+///
+/// impl Subset<FiniteChecker> for SmallChecker {}
+///
+/// let small = NoisyFloat::<f64, SmallChecker>::new(1e-9);
+/// let finite: NoisyFloat<f64, FiniteChecker> = small.widen();
+/// assert!(finite.narrow::<SmallChecker>().is_some());
+/// assert!((finite * 1e6).narrow::<SmallChecker>().is_none());
+/// ```
+pub trait Subset<C> {}
+
+impl<C> Subset<C> for C {}
+
+/// Trait for checkers whose values can be combined with values of the checker `C`.
+///
+/// `Output` is the narrowest standard checker that admits all valid values of both checkers.
+/// It is the checker of the result of an operation between a `NoisyFloat` with this checker
+/// and one with the checker `C`, such as `R64 + N64`, which returns an `N64`.
+/// As with operations between values of a single checker, the result is still checked.
+/// Every pair of standard checkers is joined, and every checker of this crate joins with itself,
+/// except `UnitChecker`, whose sums and differences leave the unit interval.
+/// A custom checker implements `Join<Self>` with `Output = Self`
+/// to support arithmetic between its values.
+pub trait Join<C> {
+    /// The checker admitting all valid values of both checkers.
+    type Output;
+}
+
+macro_rules! join_self {
+    ($($checker:ty),+) => {
+        $(
+            impl Join<$checker> for $checker {
+                type Output = $checker;
+            }
+        )+
+    };
+}
+
+join_self!(
+    NumChecker,
+    FiniteChecker,
+    PositiveChecker,
+    PositiveFiniteChecker,
+    NonNegativeChecker,
+    NonNegativeFiniteChecker,
+    NegativeChecker,
+    NegativeFiniteChecker,
+    NonPositiveChecker,
+    NonPositiveFiniteChecker,
+    NonZeroChecker,
+    NonZeroFiniteChecker,
+    NormalChecker
+);
+
+macro_rules! join {
+    ($checker:ty | $($other:ty),+ => $output:ty) => {
+        $(
+            impl Join<$other> for $checker {
+                type Output = $output;
+            }
+
+            impl Join<$checker> for $other {
+                type Output = $output;
+            }
+        )+
+    };
+}
+
+macro_rules! subset {
+    ($checker:ty => $($superset:ty),+) => {
+        $(
+            impl Subset<$superset> for $checker {}
+
+            join!($checker | $superset => $superset);
+
+            impl<F: Float> From<NoisyFloat<F, $checker>> for NoisyFloat<F, $superset> {
+                #[inline]
+                fn from(value: NoisyFloat<F, $checker>) -> Self {
+                    value.widen()
+                }
+            }
+
+            impl<F: Float> TryFrom<NoisyFloat<F, $superset>> for NoisyFloat<F, $checker> {
+                type Error = InvalidFloat<F>;
+                #[inline]
+                fn try_from(value: NoisyFloat<F, $superset>) -> Result<Self, Self::Error> {
+                    Self::try_new_checked(value.raw())
+                }
+            }
+        )+
+    };
+}
+
+subset!(FiniteChecker => NumChecker);
+subset!(PositiveChecker => NonNegativeChecker, NonZeroChecker, NumChecker);
+subset!(
+    PositiveFiniteChecker => PositiveChecker,
+    NonNegativeFiniteChecker,
+    NonNegativeChecker,
+    NonZeroFiniteChecker,
+    NonZeroChecker,
+    FiniteChecker,
+    NumChecker
+);
+subset!(NonNegativeChecker => NumChecker);
+subset!(NonNegativeFiniteChecker => NonNegativeChecker, FiniteChecker, NumChecker);
+subset!(NegativeChecker => NonPositiveChecker, NonZeroChecker, NumChecker);
+subset!(
+    NegativeFiniteChecker => NegativeChecker,
+    NonPositiveFiniteChecker,
+    NonPositiveChecker,
+    NonZeroFiniteChecker,
+    NonZeroChecker,
+    FiniteChecker,
+    NumChecker
+);
+subset!(NonPositiveChecker => NumChecker);
+subset!(NonPositiveFiniteChecker => NonPositiveChecker, FiniteChecker, NumChecker);
+subset!(NonZeroChecker => NumChecker);
+subset!(NonZeroFiniteChecker => NonZeroChecker, FiniteChecker, NumChecker);
+subset!(NormalChecker => FiniteChecker, NumChecker);
+subset!(
+    UnitChecker => SignedUnitChecker,
+    NonNegativeFiniteChecker,
+    NonNegativeChecker,
+    FiniteChecker,
+    NumChecker
+);
+subset!(SignedUnitChecker => FiniteChecker);
+
+join!(FiniteChecker | PositiveChecker, NonNegativeChecker, NegativeChecker, NonPositiveChecker, NonZeroChecker => NumChecker);
+join!(PositiveChecker | NonNegativeFiniteChecker, UnitChecker => NonNegativeChecker);
+join!(PositiveChecker | NegativeChecker, NegativeFiniteChecker, NonZeroFiniteChecker => NonZeroChecker);
+join!(PositiveChecker | NonPositiveChecker, NonPositiveFiniteChecker, NormalChecker, SignedUnitChecker => NumChecker);
+join!(PositiveFiniteChecker | NegativeChecker => NonZeroChecker);
+join!(PositiveFiniteChecker | NegativeFiniteChecker => NonZeroFiniteChecker);
+join!(PositiveFiniteChecker | NonPositiveChecker => NumChecker);
+join!(PositiveFiniteChecker | NonPositiveFiniteChecker, NormalChecker, SignedUnitChecker => FiniteChecker);
+join!(PositiveFiniteChecker | UnitChecker => NonNegativeFiniteChecker);
+join!(NonNegativeChecker | NegativeChecker, NegativeFiniteChecker, NonPositiveChecker, NonPositiveFiniteChecker, NonZeroChecker, NonZeroFiniteChecker, NormalChecker, SignedUnitChecker => NumChecker);
+join!(NonNegativeFiniteChecker | NegativeChecker, NonPositiveChecker, NonZeroChecker => NumChecker);
+join!(NonNegativeFiniteChecker | NegativeFiniteChecker, NonPositiveFiniteChecker, NonZeroFiniteChecker, NormalChecker, SignedUnitChecker => FiniteChecker);
+join!(NegativeChecker | NonPositiveFiniteChecker => NonPositiveChecker);
+join!(NegativeChecker | NonZeroFiniteChecker => NonZeroChecker);
+join!(NegativeChecker | NormalChecker, UnitChecker, SignedUnitChecker => NumChecker);
+join!(NegativeFiniteChecker | NormalChecker, UnitChecker, SignedUnitChecker => FiniteChecker);
+join!(NonPositiveChecker | NonZeroChecker, NonZeroFiniteChecker, NormalChecker, UnitChecker, SignedUnitChecker => NumChecker);
+join!(NonPositiveFiniteChecker | NonZeroChecker => NumChecker);
+join!(NonPositiveFiniteChecker | NonZeroFiniteChecker, NormalChecker, UnitChecker, SignedUnitChecker => FiniteChecker);
+join!(NonZeroChecker | NormalChecker, UnitChecker, SignedUnitChecker => NumChecker);
+join!(NonZeroFiniteChecker | NormalChecker, UnitChecker, SignedUnitChecker => FiniteChecker);
+join!(NormalChecker | UnitChecker, SignedUnitChecker => FiniteChecker);
+
+/// A `FloatChecker` that considers all values within a range valid.
+///
+/// Since floats cannot be used as const generic parameters, the bounds `LO` and `HI`
+/// are the bit patterns of `f64` values, as returned by `f64::to_bits`.
+/// `LO_INCLUSIVE` and `HI_INCLUSIVE` determine whether the bounds themselves are valid.
+/// Values are converted to `f64` before being compared against the bounds,
+/// which is exact for `f32` and `f64`.
+///
+/// The `ranged!` macro is the most convenient way to name such a type.
+/// The closed unit interval `[0, 1]` is available as `UnitChecker`.
+///
+/// The capabilities of a range are computed from its bounds,
+/// so using `Zero` or `One` with a range that does not contain
+/// zero or one fails to compile:
+///
+/// ```compile_fail
+/// use noisy_float::ranged;
+/// use num_traits::One;
+///
+/// type Fraction = ranged!(f64, -1.0 < x < 1.0);
+/// let one = Fraction::one();
+/// ```
+///
+/// The `assert` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{checkers::RangeChecker, ranged, NoisyFloat};
+///
+/// type Probability = ranged!(f64, 0.0 <= x <= 1.0);
+/// type Cosine = NoisyFloat<f64, RangeChecker<0xBFF0000000000000, 0x3FF0000000000000>>;
+///
+/// assert!(Probability::try_new(0.25).is_some());
+/// assert!(Probability::try_new(1.5).is_none());
+/// assert!(Cosine::try_new(-1.0).is_some());
+/// ```
+pub struct RangeChecker<
+    const LO: u64,
+    const HI: u64,
+    const LO_INCLUSIVE: bool = true,
+    const HI_INCLUSIVE: bool = true,
+>;
+
+impl<const LO: u64, const HI: u64, const LO_INCLUSIVE: bool, const HI_INCLUSIVE: bool>
+    RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>
+{
+    /// The lower bound of the range.
+    pub const LOWER: f64 = f64::from_bits(LO);
+
+    /// The upper bound of the range.
+    pub const UPPER: f64 = f64::from_bits(HI);
+
+    /// Returns `true` if (and only if) `value` lies within the range.
+    #[inline]
+    pub const fn contains(value: f64) -> bool {
+        let above = if LO_INCLUSIVE {
+            value >= Self::LOWER
+        } else {
+            value > Self::LOWER
+        };
+        let below = if HI_INCLUSIVE {
+            value <= Self::UPPER
+        } else {
+            value < Self::UPPER
+        };
+        above && below
+    }
+}
+
+impl<
+        F: Float,
+        const LO: u64,
+        const HI: u64,
+        const LO_INCLUSIVE: bool,
+        const HI_INCLUSIVE: bool,
+    > FloatChecker<F> for RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>
+{
+    report_asserts!(cfg!(any(debug_assertions, feature = "strict")));
+
+    const CAPABILITIES: Capabilities = {
+        let (max, min) = if mem::size_of::<F>() == mem::size_of::<f32>() {
+            (f32::MAX as f64, f32::MIN as f64)
+        } else {
+            (f64::MAX, f64::MIN)
+        };
+        let positive = Self::contains(max);
+        let negative = Self::contains(min);
+        Capabilities {
+            zero: Self::contains(0.0),
+            one: Self::contains(1.0),
+            positive,
+            negative,
+            infinity: (positive || negative)
+                && (!positive || Self::contains(f64::INFINITY))
+                && (!negative || Self::contains(f64::NEG_INFINITY)),
+        }
+    };
+
+    #[inline]
+    fn name() -> &'static str {
+        let bits = mem::size_of::<F>() * 8;
+        match (Self::LOWER, Self::UPPER, LO_INCLUSIVE && HI_INCLUSIVE, bits) {
+            (-1.0, 1.0, true, 32) => "SU32",
+            (-1.0, 1.0, true, 64) => "SU64",
+            _ => any::type_name::<Self>(),
+        }
+    }
+
+    #[inline]
+    fn message(_value: F) -> &'static str {
+        "unexpected NaN or value out of range"
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        if Self::check(value) {
+            Ok(())
+        } else if value.is_nan() {
+            Err(InvalidReason::Nan)
+        } else {
+            Err(InvalidReason::OutOfRange)
+        }
+    }
+
+    #[inline]
+    fn check(value: F) -> bool {
+        value.to_f64().is_some_and(Self::contains)
+    }
+}
+
+// Whether a range contains zero or one is only known once the bounds are,
+// so these markers are implemented for all ranges and the constants are
+// checked through `CAPABILITIES` instead.
+impl<const LO: u64, const HI: u64, const LO_INCLUSIVE: bool, const HI_INCLUSIVE: bool> AdmitsZero
+    for RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>
+{
+}
+
+impl<const LO: u64, const HI: u64, const LO_INCLUSIVE: bool, const HI_INCLUSIVE: bool> AdmitsOne
+    for RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>
+{
+}
+
+impl<const LO: u64, const HI: u64, const LO_INCLUSIVE: bool, const HI_INCLUSIVE: bool>
+    Subset<NumChecker> for RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>
+{
+}
+
+impl<const LO: u64, const HI: u64, const LO_INCLUSIVE: bool, const HI_INCLUSIVE: bool> Join<Self>
+    for RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>
+{
+    type Output = Self;
+}
+
+impl<const LO: u64, const HI: u64, const LO_INCLUSIVE: bool, const HI_INCLUSIVE: bool>
+    Join<NumChecker> for RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>
+{
+    type Output = NumChecker;
+}
+
+impl<const LO: u64, const HI: u64, const LO_INCLUSIVE: bool, const HI_INCLUSIVE: bool>
+    Join<RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>> for NumChecker
+{
+    type Output = NumChecker;
+}
+
+// Only closed ranges have a nearest valid value for every value outside of them.
+impl<F: Float, const LO: u64, const HI: u64> Clamp<F> for RangeChecker<LO, HI> {
+    #[inline]
+    fn clamp(value: F) -> F {
+        let lower = F::from(Self::LOWER).unwrap();
+        let upper = F::from(Self::UPPER).unwrap();
+        value.max(lower).min(upper)
+    }
+}
+
+impl<
+        F: Float,
+        const LO: u64,
+        const HI: u64,
+        const LO_INCLUSIVE: bool,
+        const HI_INCLUSIVE: bool,
+    > From<NoisyFloat<F, RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>>>
+    for NoisyFloat<F, NumChecker>
+{
+    #[inline]
+    fn from(value: NoisyFloat<F, RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>>) -> Self {
+        value.widen()
+    }
+}
+
+impl<
+        F: Float,
+        const LO: u64,
+        const HI: u64,
+        const LO_INCLUSIVE: bool,
+        const HI_INCLUSIVE: bool,
+    > TryFrom<NoisyFloat<F, NumChecker>>
+    for NoisyFloat<F, RangeChecker<LO, HI, LO_INCLUSIVE, HI_INCLUSIVE>>
+{
+    type Error = InvalidFloat<F>;
+    #[inline]
+    fn try_from(value: NoisyFloat<F, NumChecker>) -> Result<Self, Self::Error> {
+        Self::try_new_checked(value.raw())
+    }
+}
+
+/// Names a `NoisyFloat` type whose valid values lie within the given range.
+///
+/// The first argument is the underlying float type. The second argument describes
+/// the range as a chained comparison of literals around a placeholder identifier,
+/// where `<=` marks an inclusive bound and `<` marks an exclusive bound.
+/// Bounds are first rounded to the underlying float type.
+///
+/// The resulting type uses a `RangeChecker`.
+///
+/// # Examples
+/// ```
+/// use noisy_float::ranged;
+///
+/// type Probability = ranged!(f64, 0.0 <= p <= 1.0);
+/// type Cosine = ranged!(f32, -1.0 <= x <= 1.0);
+/// type HalfOpen = ranged!(f64, 0 <= x < 360);
+/// type Open = ranged!(f64, 0.0 < x < 1.0);
+///
+/// assert!(Probability::try_new(1.0).is_some());
+/// assert!(Cosine::try_new(-1.5).is_none());
+/// assert!(HalfOpen::try_new(360.0).is_none());
+/// assert!(Open::try_new(0.0).is_none());
+/// ```
+#[macro_export]
+macro_rules! ranged {
+    (@type $ftp:ty, $lo:literal, $hi:literal, $lo_incl:literal, $hi_incl:literal) => {
+        $crate::NoisyFloat<
+            $ftp,
+            $crate::checkers::RangeChecker<
+                { ::core::primitive::f64::to_bits($lo as $ftp as ::core::primitive::f64) },
+                { ::core::primitive::f64::to_bits($hi as $ftp as ::core::primitive::f64) },
+                $lo_incl,
+                $hi_incl,
+            >,
+        >
+    };
+    ($ftp:ty, $lo:literal <= $x:ident <= $hi:literal) => {
+        $crate::ranged!(@type $ftp, $lo, $hi, true, true)
+    };
+    ($ftp:ty, $lo:literal <= $x:ident < $hi:literal) => {
+        $crate::ranged!(@type $ftp, $lo, $hi, true, false)
+    };
+    ($ftp:ty, $lo:literal < $x:ident <= $hi:literal) => {
+        $crate::ranged!(@type $ftp, $lo, $hi, false, true)
+    };
+    ($ftp:ty, $lo:literal < $x:ident < $hi:literal) => {
+        $crate::ranged!(@type $ftp, $lo, $hi, false, false)
+    };
+}
+
+/// A `FloatChecker` that considers all values in the closed unit interval `[0, 1]` valid,
+/// such as probabilities.
+///
+/// Both +0.0 and -0.0 are considered valid.
+/// Unlike a `RangeChecker` for the same interval, it does not join with itself:
+/// products of two values of this checker stay in the unit interval and are not checked,
+/// while sums, differences, quotients and remainders are finite values.
+///
+/// The `assert` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature.
+pub struct UnitChecker;
+
+impl<F: Float> FloatChecker<F> for UnitChecker {
+    standard_asserts!("U");
+
+    #[inline]
+    fn message(_value: F) -> &'static str {
+        "unexpected NaN or value out of range"
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        if Self::check(value) {
+            Ok(())
+        } else if value.is_nan() {
+            Err(InvalidReason::Nan)
+        } else {
+            Err(InvalidReason::OutOfRange)
+        }
+    }
+
+    #[inline]
+    fn check(value: F) -> bool {
+        value >= F::zero() && value <= F::one()
+    }
+}
+
+/// A `RangeChecker` that considers all values in the closed interval `[-1, 1]` valid,
+/// such as the results of `sin` and `cos`.
+///
+/// The `assert` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature.
+pub type SignedUnitChecker = RangeChecker<0xBFF0000000000000, 0x3FF0000000000000>;
+
+/// A `FloatChecker` that considers all values valid that are valid for both checkers `A` and `B`.
+///
+/// The `assert` method asserts `A` before `B`, so that a panic message names
+/// the condition that failed. Whether `assert!` or `debug_assert!` is used
+/// is decided by each of the two checkers; see `Strict` to always check.
+/// The capability markers, such as `AdmitsZero`, are implemented if both checkers implement them,
+/// and `CAPABILITIES` is the intersection of theirs.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{
+///     checkers::{And, NormalChecker, PositiveChecker},
+///     NoisyFloat,
+/// };
+///
+/// type PositiveNormal = NoisyFloat<f64, And<PositiveChecker, NormalChecker>>;
+///
+/// assert!(PositiveNormal::try_new(1.5).is_some());
+/// assert!(PositiveNormal::try_new(-1.5).is_none());
+/// assert!(PositiveNormal::try_new(f64::MIN_POSITIVE / 2.0).is_none());
+/// ```
+pub struct And<A, B>(PhantomData<(A, B)>);
+
+impl<F: Float, A: FloatChecker<F>, B: FloatChecker<F>> FloatChecker<F> for And<A, B> {
+    const CAPABILITIES: Capabilities = A::CAPABILITIES.intersection(B::CAPABILITIES);
+
     #[track_caller]
     #[inline]
     fn assert(value: F) {
-        debug_assert!(Self::check(value), "unexpected NaN or infinity");
+        A::assert(value);
+        B::assert(value);
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_or_replace(value: F) -> F {
+        B::assert_or_replace(A::assert_or_replace(value))
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_operation(value: F, operation: Operation<F>) -> F {
+        B::assert_operation(A::assert_operation(value, operation), operation)
     }
 
     #[inline]
     fn check(value: F) -> bool {
-        value.is_finite()
+        A::check(value) && B::check(value)
+    }
+
+    #[inline]
+    fn message(value: F) -> &'static str {
+        if A::check(value) {
+            B::message(value)
+        } else {
+            A::message(value)
+        }
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        A::validate(value)?;
+        B::validate(value)
     }
 }
 
-impl<F: Float> From<NoisyFloat<F, FiniteChecker>> for NoisyFloat<F, NumChecker> {
+impl<A: AdmitsZero, B: AdmitsZero> AdmitsZero for And<A, B> {}
+
+impl<A: AdmitsOne, B: AdmitsOne> AdmitsOne for And<A, B> {}
+
+impl<A: AdmitsPositive, B: AdmitsPositive> AdmitsPositive for And<A, B> {}
+
+impl<A: AdmitsNegative, B: AdmitsNegative> AdmitsNegative for And<A, B> {}
+
+impl<A: AdmitsInfinity, B: AdmitsInfinity> AdmitsInfinity for And<A, B> {}
+
+impl<A, B> Join<Self> for And<A, B> {
+    type Output = Self;
+}
+
+/// A `FloatChecker` that considers the same values valid as the checker `C`,
+/// but whose `assert` method is implemented using `assert!`.
+///
+/// Values are therefore also checked in optimized builds,
+/// and the panic message and name are the ones of `C`.
+/// `Strict<C>` implements the same capability markers as `C`,
+/// and converts to and from `C` with `NoisyFloat::widen`.
+///
+/// # Examples
+/// ```should_panic
+/// use noisy_float::{
+///     checkers::{FiniteChecker, Strict},
+///     NoisyFloat,
+/// };
+///
+/// type StrictR64 = NoisyFloat<f64, Strict<FiniteChecker>>;
+///
+/// // panics even in release builds
+/// let _ = StrictR64::new(1.0) / 0.0;
+/// ```
+pub struct Strict<C>(PhantomData<C>);
+
+impl<F: Float, C: FloatChecker<F>> FloatChecker<F> for Strict<C> {
+    const CAPABILITIES: Capabilities = C::CAPABILITIES;
+
+    report_asserts!(true);
+
+    #[inline]
+    fn check(value: F) -> bool {
+        C::check(value)
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        C::name()
+    }
+
+    #[inline]
+    fn message(value: F) -> &'static str {
+        C::message(value)
+    }
+
     #[inline]
-    fn from(value: NoisyFloat<F, FiniteChecker>) -> Self {
-        Self::unchecked_new_generic(value.raw())
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        C::validate(value)
     }
 }
 
-impl<F: Float> TryFrom<NoisyFloat<F, NumChecker>> for NoisyFloat<F, FiniteChecker> {
-    type Error = &'static str;
+impl<C: AdmitsZero> AdmitsZero for Strict<C> {}
+
+impl<C: AdmitsOne> AdmitsOne for Strict<C> {}
+
+impl<C: AdmitsPositive> AdmitsPositive for Strict<C> {}
+
+impl<C: AdmitsNegative> AdmitsNegative for Strict<C> {}
+
+impl<C: AdmitsInfinity> AdmitsInfinity for Strict<C> {}
+
+impl<F: Float, C: Clamp<F>> Clamp<F> for Strict<C> {
+    #[inline]
+    fn clamp(value: F) -> F {
+        C::clamp(value)
+    }
+}
+
+impl<C> Subset<C> for Strict<C> {}
+
+impl<C> Subset<Strict<C>> for C {}
+
+impl<C> Join<Self> for Strict<C> {
+    type Output = Self;
+}
+
+/// A `FloatChecker` that considers the same values valid as the checker `C`,
+/// but whose `assert` method raises sticky flags instead of panicking.
+///
+/// An operation on finite values with an infinite result raises `Flags::DIVIDE_BY_ZERO`
+/// if the result is an exact infinity, such as for `1 / 0` or `ln(0)`,
+/// and `Flags::OVERFLOW` otherwise, even if `C` considers infinities valid.
+/// Any other *invalid* value raises `Flags::OVERFLOW` if it is infinite,
+/// and `Flags::INVALID` otherwise, such as for NaN.
+/// Any subnormal value raises `Flags::SUBNORMAL`, even if `C` considers it valid.
+/// The *invalid* value is kept, so a `NoisyFloat` with this checker
+/// may hold a value such as NaN; see the `flags` module to find out.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{
+///     checkers::{Flagged, NormalChecker},
+///     flags::{self, Flags},
+///     NoisyFloat,
+/// };
+///
+/// type FlaggedNormal = NoisyFloat<f64, Flagged<NormalChecker>>;
+///
+/// flags::clear();
+/// let tiny = FlaggedNormal::new(f64::MIN_POSITIVE) / 4.0;
+/// assert!(tiny.raw() > 0.0);
+/// assert_eq!(flags::take(), Flags::SUBNORMAL | Flags::INVALID);
+/// ```
+pub struct Flagged<C>(PhantomData<C>);
+
+impl<C> Flagged<C> {
+    #[inline]
+    fn raise<F: Float>(value: F, operation: Option<Operation<F>>)
+    where
+        C: FloatChecker<F>,
+    {
+        let mut raised = Flags::NONE;
+        if value.classify() == FpCategory::Subnormal {
+            raised |= Flags::SUBNORMAL;
+        }
+        match operation {
+            Some(operation)
+                if value.is_infinite() && operation.operands().iter().all(|x| x.is_finite()) =>
+            {
+                raised |= if Self::is_pole(operation) {
+                    Flags::DIVIDE_BY_ZERO
+                } else {
+                    Flags::OVERFLOW
+                };
+            }
+            _ if !C::check(value) => {
+                raised |= if value.is_infinite() {
+                    Flags::OVERFLOW
+                } else {
+                    Flags::INVALID
+                };
+            }
+            _ => {}
+        }
+        flags::raise(raised);
+    }
+
+    /// Returns `true` if the operation on finite operands has an exact infinite result.
     #[inline]
-    fn try_from(f: NoisyFloat<F, NumChecker>) -> Result<Self, Self::Error> {
-        Self::try_new(f.value).ok_or("illegal value")
+    fn is_pole<F: Float>(operation: Operation<F>) -> bool {
+        match (operation.name(), operation.operands()) {
+            ("/", [lhs, rhs]) => !lhs.is_zero() && rhs.is_zero(),
+            ("recip" | "ln" | "log2" | "log10" | "powi" | "powf", [x, ..]) => x.is_zero(),
+            ("log", [x, base]) => x.is_zero() || *base == F::one(),
+            ("ln_1p", [x]) => *x == -F::one(),
+            ("atanh", [x]) => x.abs() == F::one(),
+            _ => false,
+        }
     }
-}
\ No newline at end of file
+}
+
+impl<F: Float, C: FloatChecker<F>> FloatChecker<F> for Flagged<C> {
+    const CAPABILITIES: Capabilities = C::CAPABILITIES;
+
+    #[inline]
+    fn assert(value: F) {
+        Self::raise(value, None);
+    }
+
+    #[inline]
+    fn assert_operation(value: F, operation: Operation<F>) -> F {
+        Self::raise(value, Some(operation));
+        value
+    }
+
+    #[inline]
+    fn check(value: F) -> bool {
+        C::check(value)
+    }
+
+    #[inline]
+    fn message(value: F) -> &'static str {
+        C::message(value)
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        C::validate(value)
+    }
+}
+
+impl<C: AdmitsZero> AdmitsZero for Flagged<C> {}
+
+impl<C: AdmitsOne> AdmitsOne for Flagged<C> {}
+
+impl<C: AdmitsPositive> AdmitsPositive for Flagged<C> {}
+
+impl<C: AdmitsNegative> AdmitsNegative for Flagged<C> {}
+
+impl<C: AdmitsInfinity> AdmitsInfinity for Flagged<C> {}
+
+impl<C> Subset<Flagged<C>> for C {}
+
+impl<C> Join<Self> for Flagged<C> {
+    type Output = Self;
+}
+
+/// A `FloatChecker` that considers the same values valid as the checker `C`,
+/// but whose `assert` method only checks 1 in N values, in all builds,
+/// and never panics.
+///
+/// The first violation found at each call site is reported
+/// to the callback of the `sampling` module, which also configures the rate.
+/// This keeps a cheap probe on in optimized builds, where checking every value
+/// with `Strict` would cost too much.
+/// The name of the checker is the one of `C`.
+pub struct Sampled<C>(PhantomData<C>);
+
+impl<F: Float, C: FloatChecker<F>> FloatChecker<F> for Sampled<C> {
+    const CAPABILITIES: Capabilities = C::CAPABILITIES;
+
+    #[track_caller]
+    #[inline]
+    fn assert(value: F) {
+        if sampling::sample() && !checked::<F, Self>(value) {
+            sampling::report::<F, Self>(value, None);
+        }
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_operation(value: F, operation: Operation<F>) -> F {
+        if sampling::sample() && !checked::<F, Self>(value) {
+            sampling::report::<F, Self>(value, Some(operation));
+        }
+        value
+    }
+
+    #[inline]
+    fn check(value: F) -> bool {
+        C::check(value)
+    }
+
+    #[inline]
+    fn message(value: F) -> &'static str {
+        C::message(value)
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        C::name()
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        C::validate(value)
+    }
+}
+
+impl<C: AdmitsZero> AdmitsZero for Sampled<C> {}
+
+impl<C: AdmitsOne> AdmitsOne for Sampled<C> {}
+
+impl<C: AdmitsPositive> AdmitsPositive for Sampled<C> {}
+
+impl<C: AdmitsNegative> AdmitsNegative for Sampled<C> {}
+
+impl<C: AdmitsInfinity> AdmitsInfinity for Sampled<C> {}
+
+impl<C> Subset<Sampled<C>> for C {}
+
+impl<C> Join<Self> for Sampled<C> {
+    type Output = Self;
+}
+
+/// A `FloatChecker` that behaves like the checker `C`,
+/// and also records the range of the values it is asked to check for each call site,
+/// with the `profiling` feature.
+///
+/// The smallest and largest absolute values, and the numbers of subnormals and zeros,
+/// are returned by `stats::ranges`.
+/// Swapping the checker of a type alias for `RangeProfiler<C>`
+/// shows which computations would fit in a narrower type, such as `f32`.
+/// The name of the checker is the one of `C`.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{checkers::{FiniteChecker, RangeProfiler}, stats, NoisyFloat};
+///
+/// type R64 = NoisyFloat<f64, RangeProfiler<FiniteChecker>>;
+///
+/// let scale = R64::new(1e-3);
+/// let scaled: Vec<R64> = [0.0, 2.0, 5e3].iter().map(|&x| scale * x).collect();
+/// assert_eq!(scaled[2], 5.0);
+///
+/// let range = stats::ranges()
+///     .into_iter()
+///     .find(|site| site.values() == 3)
+///     .unwrap();
+/// assert_eq!(range.checker(), "R64");
+/// assert_eq!((range.min_abs(), range.max_abs()), (Some(2e-3), Some(5.0)));
+/// assert_eq!((range.subnormals(), range.zeros()), (0, 1));
+/// assert!(range.fits_f32());
+/// ```
+#[cfg(feature = "profiling")]
+pub struct RangeProfiler<C>(PhantomData<C>);
+
+#[cfg(feature = "profiling")]
+impl<F: Float, C: FloatChecker<F>> FloatChecker<F> for RangeProfiler<C> {
+    const CAPABILITIES: Capabilities = C::CAPABILITIES;
+
+    #[track_caller]
+    #[inline]
+    fn assert(value: F) {
+        crate::stats::record_range::<F, Self>(value);
+        C::assert(value);
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_or_replace(value: F) -> F {
+        crate::stats::record_range::<F, Self>(value);
+        C::assert_or_replace(value)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_operation(value: F, operation: Operation<F>) -> F {
+        crate::stats::record_range::<F, Self>(value);
+        C::assert_operation(value, operation)
+    }
+
+    #[inline]
+    fn check(value: F) -> bool {
+        C::check(value)
+    }
+
+    #[inline]
+    fn message(value: F) -> &'static str {
+        C::message(value)
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        C::name()
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        C::validate(value)
+    }
+}
+
+#[cfg(feature = "profiling")]
+impl<C: AdmitsZero> AdmitsZero for RangeProfiler<C> {}
+
+#[cfg(feature = "profiling")]
+impl<C: AdmitsOne> AdmitsOne for RangeProfiler<C> {}
+
+#[cfg(feature = "profiling")]
+impl<C: AdmitsPositive> AdmitsPositive for RangeProfiler<C> {}
+
+#[cfg(feature = "profiling")]
+impl<C: AdmitsNegative> AdmitsNegative for RangeProfiler<C> {}
+
+#[cfg(feature = "profiling")]
+impl<C: AdmitsInfinity> AdmitsInfinity for RangeProfiler<C> {}
+
+#[cfg(feature = "profiling")]
+impl<C> Subset<RangeProfiler<C>> for C {}
+
+#[cfg(feature = "profiling")]
+impl<C> Join<Self> for RangeProfiler<C> {
+    type Output = Self;
+}
+
+/// A `FloatChecker` that considers the same values valid as the checker `C`,
+/// and also rejects the result of a subtraction, or of an addition of operands
+/// with opposite signs, that lost more than `BITS` significant bits to cancellation.
+///
+/// The bits lost are those by which the result is smaller than the larger operand,
+/// so that `1.0000001 - 1.0` loses 23 bits, and an exact zero from equal operands
+/// loses all of them.
+/// Such results are finite and valid for `C`, but carry little of the precision
+/// of the operands, as in a finite difference with too small a step.
+///
+/// The cancellation is only checked by the operators, whose operands are known,
+/// and is reported to the violation handler.
+/// The `assert_operation` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature, like the standard checkers.
+/// The name of the checker is the one of `C`.
+///
+/// # Examples
+/// ```no_run
+/// use noisy_float::{checkers::{Cancellation, FiniteChecker}, NoisyFloat};
+///
+/// type R64 = NoisyFloat<f64, Cancellation<FiniteChecker, 20>>;
+///
+/// let f = |x: R64| x * x;
+/// let (x, h) = (R64::new(1.0), 1e-10);
+/// let slope = (f(x + h) - f(x)) / h; // panics in debug builds
+/// ```
+pub struct Cancellation<C, const BITS: u32>(PhantomData<C>);
+
+impl<C, const BITS: u32> Cancellation<C, BITS> {
+    /// Returns `true` if the result of the operation lost more than `BITS` bits to cancellation.
+    fn cancels<F: Float>(value: F, operation: &Operation<F>) -> bool {
+        let (lhs, rhs) = match (operation.name(), operation.operands()) {
+            ("+", &[lhs, rhs]) => (lhs, rhs),
+            ("-", &[lhs, rhs]) => (lhs, -rhs),
+            _ => return false,
+        };
+        let larger = lhs.abs().max(rhs.abs());
+        lhs.is_sign_positive() != rhs.is_sign_positive()
+            && !lhs.is_zero()
+            && !rhs.is_zero()
+            && larger.is_finite()
+            && value.is_finite()
+            && larger / value.abs() > F::from(2.0).unwrap().powi(BITS as i32)
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>, const BITS: u32> FloatChecker<F> for Cancellation<C, BITS> {
+    const CAPABILITIES: Capabilities = C::CAPABILITIES;
+
+    #[track_caller]
+    #[inline]
+    fn assert(value: F) {
+        C::assert(value);
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_or_replace(value: F) -> F {
+        C::assert_or_replace(value)
+    }
+
+    #[track_caller]
+    #[inline]
+    fn assert_operation(value: F, operation: Operation<F>) -> F {
+        let value = C::assert_operation(value, operation);
+        if cfg!(any(debug_assertions, feature = "strict")) && Self::cancels(value, &operation) {
+            violation::report_cancellation::<F, Self>(value, operation)
+        } else {
+            value
+        }
+    }
+
+    #[inline]
+    fn check(value: F) -> bool {
+        C::check(value)
+    }
+
+    #[inline]
+    fn message(value: F) -> &'static str {
+        C::message(value)
+    }
+
+    #[inline]
+    fn name() -> &'static str {
+        C::name()
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        C::validate(value)
+    }
+}
+
+impl<C: AdmitsZero, const BITS: u32> AdmitsZero for Cancellation<C, BITS> {}
+
+impl<C: AdmitsOne, const BITS: u32> AdmitsOne for Cancellation<C, BITS> {}
+
+impl<C: AdmitsPositive, const BITS: u32> AdmitsPositive for Cancellation<C, BITS> {}
+
+impl<C: AdmitsNegative, const BITS: u32> AdmitsNegative for Cancellation<C, BITS> {}
+
+impl<C: AdmitsInfinity, const BITS: u32> AdmitsInfinity for Cancellation<C, BITS> {}
+
+impl<C, const BITS: u32> Subset<Cancellation<C, BITS>> for C {}
+
+impl<C, const BITS: u32> Join<Self> for Cancellation<C, BITS> {
+    type Output = Self;
+}
+
+/// Trait for custom conditions on floating point values, checked by `Satisfies`.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{
+///     checkers::{And, FiniteChecker, Predicate, Satisfies},
+///     NoisyFloat,
+/// };
+///
+/// struct Integer;
+///
+/// impl Predicate for Integer {
+///     const MESSAGE: &'static str = "unexpected NaN or fractional value";
+///
+///     fn test(value: f64) -> bool {
+///         value.fract() == 0.0
+///     }
+/// }
+///
+/// type Whole = NoisyFloat<f64, And<FiniteChecker, Satisfies<Integer>>>;
+///
+/// assert!(Whole::try_new(3.0).is_some());
+/// assert!(Whole::try_new(3.5).is_none());
+/// assert!(Whole::try_new(f64::INFINITY).is_none());
+/// ```
+pub trait Predicate {
+    /// The panic message for values that do not satisfy the predicate.
+    const MESSAGE: &'static str = "unexpected NaN or value not satisfying the predicate";
+
+    /// Returns `true` if (and only if) the value satisfies the predicate.
+    ///
+    /// This is never called with NaN.
+    fn test(value: f64) -> bool;
+}
+
+/// A `FloatChecker` that considers all values valid that satisfy the `Predicate` `P`.
+///
+/// NaN is considered invalid regardless of the predicate.
+/// Values are converted to `f64` before being tested,
+/// which is exact for `f32` and `f64`.
+///
+/// The `assert` method is implemented using `debug_assert!`,
+/// or `assert!` with the `strict` feature.
+pub struct Satisfies<P>(PhantomData<P>);
+
+impl<F: Float, P: Predicate> FloatChecker<F> for Satisfies<P> {
+    report_asserts!(cfg!(any(debug_assertions, feature = "strict")));
+
+    #[inline]
+    fn check(value: F) -> bool {
+        value
+            .to_f64()
+            .is_some_and(|value| !value.is_nan() && P::test(value))
+    }
+
+    #[inline]
+    fn message(_value: F) -> &'static str {
+        P::MESSAGE
+    }
+
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason> {
+        if Self::check(value) {
+            Ok(())
+        } else if value.is_nan() {
+            Err(InvalidReason::Nan)
+        } else {
+            Err(InvalidReason::Custom(P::MESSAGE))
+        }
+    }
+}
+
+impl<P> Join<Self> for Satisfies<P> {
+    type Output = Self;
+}
@@ -0,0 +1,269 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use core::fmt;
+use num_traits::Float;
+
+/// The reason why a `FloatChecker` considers a value *invalid*.
+///
+/// Returned by `FloatChecker::validate`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InvalidReason {
+    /// The value is NaN.
+    Nan,
+    /// The value is +Infinity.
+    PosInfinity,
+    /// The value is -Infinity.
+    NegInfinity,
+    /// The value is +0.0 or -0.0.
+    Zero,
+    /// The value is a subnormal number.
+    Subnormal,
+    /// The value lies outside of the valid range, such as a negative value
+    /// for a non-negative checker.
+    OutOfRange,
+    /// The value is rejected for a reason described by the message.
+    Custom(&'static str),
+}
+
+impl InvalidReason {
+    /// Returns the reason for rejecting `value` based on its category alone:
+    /// NaN, an infinity, zero, or otherwise a value out of range.
+    #[inline]
+    pub fn classify<F: Float>(value: F) -> Self {
+        if value.is_nan() {
+            InvalidReason::Nan
+        } else if value.is_infinite() {
+            if value.is_sign_positive() {
+                InvalidReason::PosInfinity
+            } else {
+                InvalidReason::NegInfinity
+            }
+        } else if value.is_zero() {
+            InvalidReason::Zero
+        } else {
+            InvalidReason::OutOfRange
+        }
+    }
+}
+
+impl fmt::Display for InvalidReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.write_str(match self {
+            InvalidReason::Nan => "NaN",
+            InvalidReason::PosInfinity => "positive infinity",
+            InvalidReason::NegInfinity => "negative infinity",
+            InvalidReason::Zero => "zero",
+            InvalidReason::Subnormal => "subnormal value",
+            InvalidReason::OutOfRange => "value out of range",
+            InvalidReason::Custom(message) => message,
+        })
+    }
+}
+
+/// An operation on floating point values, such as `0 / 0` or `ln(-3.2)`.
+///
+/// Recorded by `InvalidFloat` when the result of the operation was rejected.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Operation<F> {
+    name: &'static str,
+    operands: [F; 3],
+    arity: usize,
+}
+
+impl<F: Copy> Operation<F> {
+    /// Constructs an `Operation` from the name of an operator or method
+    /// and between one and three operands, the first of which is the receiver.
+    #[inline]
+    pub(crate) fn new(name: &'static str, operands: &[F]) -> Self {
+        let mut padded = [operands[0]; 3];
+        padded[..operands.len()].copy_from_slice(operands);
+        Operation {
+            name,
+            operands: padded,
+            arity: operands.len(),
+        }
+    }
+
+    /// Converts each of the operands.
+    #[inline]
+    pub(crate) fn map<G: Copy>(self, mut f: impl FnMut(F) -> G) -> Operation<G> {
+        let [a, b, c] = self.operands;
+        Operation {
+            name: self.name,
+            operands: [f(a), f(b), f(c)],
+            arity: self.arity,
+        }
+    }
+
+    /// Returns the operator symbol, such as `"/"`, or the method name, such as `"ln"`.
+    #[inline]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the operands, starting with the receiver of the method.
+    #[inline]
+    pub fn operands(&self) -> &[F] {
+        &self.operands[..self.arity]
+    }
+}
+
+impl<F: Copy + fmt::Display> fmt::Display for Operation<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match (self.name, self.operands()) {
+            ("+" | "-" | "*" | "/" | "%", [lhs, rhs]) => write!(f, "{} {} {}", lhs, self.name, rhs),
+            ("-", [value]) => write!(f, "-{}", value),
+            (name, operands) => {
+                write!(f, "{}(", name)?;
+                for (i, operand) in operands.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    fmt::Display::fmt(operand, f)?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The error returned when constructing a `NoisyFloat` from an *invalid* value.
+///
+/// It carries the rejected value together with the reason given by the `FloatChecker`,
+/// and the `Operation` that produced the value if it is known,
+/// such as for the `checked_*` methods.
+/// With the `std` feature, it implements `std::error::Error`.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{prelude::*, InvalidReason};
+///
+/// let error = R64::try_new_checked(f64::NEG_INFINITY).unwrap_err();
+/// assert_eq!(error.reason(), InvalidReason::NegInfinity);
+/// assert_eq!(error.to_string(), "invalid value -inf (negative infinity)");
+///
+/// let error = r64(-3.0).checked_ln().unwrap_err();
+/// assert_eq!(error.to_string(), "invalid value NaN (NaN) produced by ln(-3)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct InvalidFloat<F> {
+    value: F,
+    reason: InvalidReason,
+    operation: Option<Operation<F>>,
+}
+
+impl<F: Copy> InvalidFloat<F> {
+    /// Constructs an `InvalidFloat` from the rejected value and the reason.
+    #[inline]
+    pub fn new(value: F, reason: InvalidReason) -> Self {
+        InvalidFloat {
+            value,
+            reason,
+            operation: None,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn with_operation(self, operation: Operation<F>) -> Self {
+        InvalidFloat {
+            operation: Some(operation),
+            ..self
+        }
+    }
+
+    /// Returns the rejected value.
+    #[inline]
+    pub fn value(&self) -> F {
+        self.value
+    }
+
+    /// Returns the reason why the value was rejected.
+    #[inline]
+    pub fn reason(&self) -> InvalidReason {
+        self.reason
+    }
+
+    /// Returns the operation that produced the rejected value, if it is known.
+    #[inline]
+    pub fn operation(&self) -> Option<&Operation<F>> {
+        self.operation.as_ref()
+    }
+}
+
+impl<F: Copy + fmt::Display> fmt::Display for InvalidFloat<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "invalid value {} ({})", self.value, self.reason)?;
+        if let Some(operation) = &self.operation {
+            write!(f, " produced by {}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<F: Copy + fmt::Debug + fmt::Display> std::error::Error for InvalidFloat<F> {}
+
+/// The error returned when parsing a `NoisyFloat` from a string.
+///
+/// It distinguishes a string that is not a float literal, with the error `E`
+/// of the underlying float type, from a float literal whose value is rejected
+/// by the `FloatChecker`, such as `"inf"` for an `R64`.
+/// With the `std` feature, it implements `std::error::Error`.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{prelude::*, InvalidReason, ParseError};
+///
+/// assert_eq!("1.5".parse::<R64>(), Ok(r64(1.5)));
+/// assert!(matches!("1.5.0".parse::<R64>(), Err(ParseError::Syntax(_))));
+///
+/// let error = "inf".parse::<R64>().unwrap_err();
+/// match error {
+///     ParseError::Invalid(ref invalid) => assert_eq!(invalid.reason(), InvalidReason::PosInfinity),
+///     ParseError::Syntax(_) => unreachable!(),
+/// }
+/// assert_eq!(error.to_string(), "invalid value inf (positive infinity)");
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseError<E, F> {
+    /// The string is not a float literal.
+    Syntax(E),
+    /// The float literal has a value that the `FloatChecker` rejects.
+    Invalid(InvalidFloat<F>),
+}
+
+impl<E: fmt::Display, F: Copy + fmt::Display> fmt::Display for ParseError<E, F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self {
+            ParseError::Syntax(error) => fmt::Display::fmt(error, f),
+            ParseError::Invalid(error) => fmt::Display::fmt(error, f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E, F> std::error::Error for ParseError<E, F>
+where
+    E: std::error::Error + 'static,
+    F: Copy + fmt::Debug + fmt::Display + 'static,
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Syntax(error) => Some(error),
+            ParseError::Invalid(error) => Some(error),
+        }
+    }
+}
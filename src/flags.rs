@@ -0,0 +1,196 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sticky exception flags, in the manner of IEEE 754.
+//!
+//! Values with a `Flagged` checker never panic.
+//! Instead, each *invalid* value raises a flag in a status word,
+//! which stays raised until it is cleared,
+//! so that a whole computation can be checked once at the end.
+//! The status word is thread-local with the `std` feature,
+//! and otherwise a single atomic shared by all threads.
+//!
+//! # Examples
+//! ```
+//! use noisy_float::{flags::{self, Flags}, prelude::*};
+//!
+//! flags::clear();
+//! let x = FlaggedR64::new(1.0);
+//! let ratios = [x / 4.0, x / 0.0, x * f64::MAX * 2.0];
+//! assert!(ratios[1].raw().is_infinite());
+//!
+//! let raised = flags::take();
+//! assert!(raised.contains(Flags::DIVIDE_BY_ZERO | Flags::OVERFLOW));
+//! assert!(!raised.contains(Flags::INVALID));
+//! assert!(!flags::test(Flags::ALL));
+//! ```
+
+use core::{fmt, ops};
+
+/// A set of exception flags.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Flags(u8);
+
+impl Flags {
+    /// No flags.
+    pub const NONE: Flags = Flags(0);
+    /// An operation produced NaN, or another value rejected by the checker.
+    pub const INVALID: Flags = Flags(1);
+    /// A non-zero finite value was divided by zero, producing an infinity.
+    pub const DIVIDE_BY_ZERO: Flags = Flags(1 << 1);
+    /// An operation produced an infinity rejected by the checker,
+    /// other than by dividing by zero.
+    pub const OVERFLOW: Flags = Flags(1 << 2);
+    /// An operation produced a subnormal number, whether the checker accepts it or not.
+    pub const SUBNORMAL: Flags = Flags(1 << 3);
+    /// All flags.
+    pub const ALL: Flags = Flags(0b1111);
+
+    /// Returns `true` if no flag is set.
+    #[inline]
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns `true` if all of the given flags are set.
+    #[inline]
+    pub fn contains(self, other: Flags) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Returns `true` if any of the given flags is set.
+    #[inline]
+    pub fn intersects(self, other: Flags) -> bool {
+        self.0 & other.0 != 0
+    }
+}
+
+impl ops::BitOr for Flags {
+    type Output = Flags;
+    #[inline]
+    fn bitor(self, rhs: Flags) -> Flags {
+        Flags(self.0 | rhs.0)
+    }
+}
+
+impl ops::BitOrAssign for Flags {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Flags) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl ops::BitAnd for Flags {
+    type Output = Flags;
+    #[inline]
+    fn bitand(self, rhs: Flags) -> Flags {
+        Flags(self.0 & rhs.0)
+    }
+}
+
+impl fmt::Debug for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let names = [
+            (Flags::INVALID, "INVALID"),
+            (Flags::DIVIDE_BY_ZERO, "DIVIDE_BY_ZERO"),
+            (Flags::OVERFLOW, "OVERFLOW"),
+            (Flags::SUBNORMAL, "SUBNORMAL"),
+        ];
+        let mut set = f.debug_set();
+        for (flag, name) in names.iter() {
+            if self.contains(*flag) {
+                set.entry(&format_args!("{}", name));
+            }
+        }
+        set.finish()
+    }
+}
+
+#[cfg(feature = "std")]
+mod status {
+    use core::cell::Cell;
+
+    std::thread_local! {
+        static STATUS: Cell<u8> = const { Cell::new(0) };
+    }
+
+    #[inline]
+    pub(super) fn raise(bits: u8) {
+        STATUS.with(|status| status.set(status.get() | bits));
+    }
+
+    #[inline]
+    pub(super) fn get() -> u8 {
+        STATUS.with(Cell::get)
+    }
+
+    #[inline]
+    pub(super) fn take() -> u8 {
+        STATUS.with(|status| status.replace(0))
+    }
+}
+
+#[cfg(not(feature = "std"))]
+mod status {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    static STATUS: AtomicU8 = AtomicU8::new(0);
+
+    #[inline]
+    pub(super) fn raise(bits: u8) {
+        STATUS.fetch_or(bits, Ordering::Relaxed);
+    }
+
+    #[inline]
+    pub(super) fn get() -> u8 {
+        STATUS.load(Ordering::Relaxed)
+    }
+
+    #[inline]
+    pub(super) fn take() -> u8 {
+        STATUS.swap(0, Ordering::Relaxed)
+    }
+}
+
+/// Raises the given flags.
+#[inline]
+pub fn raise(flags: Flags) {
+    if !flags.is_empty() {
+        status::raise(flags.0);
+    }
+}
+
+/// Returns the flags raised since they were last cleared, without clearing them.
+#[inline]
+pub fn get() -> Flags {
+    Flags(status::get())
+}
+
+/// Returns the flags raised since they were last cleared, and clears them.
+#[inline]
+pub fn take() -> Flags {
+    Flags(status::take())
+}
+
+/// Clears all flags.
+#[inline]
+pub fn clear() {
+    status::take();
+}
+
+/// Returns `true` if any of the given flags has been raised since the flags were last cleared.
+#[inline]
+pub fn test(flags: Flags) -> bool {
+    get().intersects(flags)
+}
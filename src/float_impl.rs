@@ -12,15 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{FloatChecker, NoisyFloat};
+use crate::{
+    checkers::{Admits, FiniteChecker, Join, UnitChecker},
+    FloatChecker, InvalidFloat, NoisyFloat, ParseError,
+};
 use core::{
     cmp::Ordering,
     convert::{From, TryFrom},
     hash::{Hash, Hasher},
     iter,
-    mem::transmute,
     num::FpCategory,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    str::FromStr,
 };
 use num_traits::{
     cast::{FromPrimitive, NumCast, ToPrimitive},
@@ -31,7 +34,7 @@ use num_traits::{
 impl<F: Float, C: FloatChecker<F>> Clone for NoisyFloat<F, C> {
     #[inline]
     fn clone(&self) -> Self {
-        Self::unchecked_new_generic(self.value)
+        *self
     }
 }
 
@@ -46,13 +49,15 @@ impl<F: Float, C: FloatChecker<F>> AsRef<F> for NoisyFloat<F, C> {
 impl<F: Float, C: FloatChecker<F>> PartialEq<F> for NoisyFloat<F, C> {
     #[inline]
     fn eq(&self, other: &F) -> bool {
-        self.value.eq(&other)
+        self.value.eq(other)
     }
 }
 
-impl<F: Float, C: FloatChecker<F>> PartialEq for NoisyFloat<F, C> {
+impl<F: Float, C: FloatChecker<F>, C2: FloatChecker<F>> PartialEq<NoisyFloat<F, C2>>
+    for NoisyFloat<F, C>
+{
     #[inline]
-    fn eq(&self, other: &Self) -> bool {
+    fn eq(&self, other: &NoisyFloat<F, C2>) -> bool {
         self.eq(&other.value)
     }
 }
@@ -62,45 +67,47 @@ impl<F: Float, C: FloatChecker<F>> Eq for NoisyFloat<F, C> {}
 impl<F: Float, C: FloatChecker<F>> PartialOrd<F> for NoisyFloat<F, C> {
     #[inline]
     fn partial_cmp(&self, other: &F) -> Option<Ordering> {
-        self.value.partial_cmp(&other)
+        self.value.partial_cmp(other)
     }
     #[inline]
     fn lt(&self, other: &F) -> bool {
-        self.value.lt(&other)
+        self.value.lt(other)
     }
     #[inline]
     fn le(&self, other: &F) -> bool {
-        self.value.le(&other)
+        self.value.le(other)
     }
     #[inline]
     fn gt(&self, other: &F) -> bool {
-        self.value.gt(&other)
+        self.value.gt(other)
     }
     #[inline]
     fn ge(&self, other: &F) -> bool {
-        self.value.ge(&other)
+        self.value.ge(other)
     }
 }
 
-impl<F: Float, C: FloatChecker<F>> PartialOrd for NoisyFloat<F, C> {
+impl<F: Float, C: FloatChecker<F>, C2: FloatChecker<F>> PartialOrd<NoisyFloat<F, C2>>
+    for NoisyFloat<F, C>
+{
     #[inline]
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    fn partial_cmp(&self, other: &NoisyFloat<F, C2>) -> Option<Ordering> {
         self.value.partial_cmp(&other.value)
     }
     #[inline]
-    fn lt(&self, other: &Self) -> bool {
+    fn lt(&self, other: &NoisyFloat<F, C2>) -> bool {
         self.lt(&other.value)
     }
     #[inline]
-    fn le(&self, other: &Self) -> bool {
+    fn le(&self, other: &NoisyFloat<F, C2>) -> bool {
         self.le(&other.value)
     }
     #[inline]
-    fn gt(&self, other: &Self) -> bool {
+    fn gt(&self, other: &NoisyFloat<F, C2>) -> bool {
         self.gt(&other.value)
     }
     #[inline]
-    fn ge(&self, other: &Self) -> bool {
+    fn ge(&self, other: &NoisyFloat<F, C2>) -> bool {
         self.ge(&other.value)
     }
 }
@@ -124,7 +131,7 @@ impl<C: FloatChecker<f32>> Hash for NoisyFloat<f32, C> {
         let bits = if self.value == 0.0 {
             0 // this accounts for +0.0 and -0.0
         } else {
-            unsafe { transmute::<f32, u32>(self.value) }
+            self.value.to_bits()
         };
         bits.hash(state);
     }
@@ -136,7 +143,7 @@ impl<C: FloatChecker<f64>> Hash for NoisyFloat<f64, C> {
         let bits = if self.value == 0.0 {
             0 // this accounts for +0.0 and -0.0
         } else {
-            unsafe { transmute::<f64, u64>(self.value) }
+            self.value.to_bits()
         };
         bits.hash(state);
     }
@@ -179,65 +186,102 @@ macro_rules! op_impl {
 op_impl!(
     (F: Float, C: FloatChecker<F>), Add, (NoisyFloat<F, C>, F),
     add(self, rhs: F) -> NoisyFloat<F, C> {
-        NoisyFloat::new(self.value.add(rhs))
+        NoisyFloat::new_from_operation(self.value.add(rhs), "+", &[self.value, rhs])
     }
 );
 op_impl!(
-    (F: Float, C: FloatChecker<F>), Add, (NoisyFloat<F, C>, NoisyFloat<F, C>),
-    add(self, rhs: NoisyFloat<F, C>) -> NoisyFloat<F, C> {
-        self.add(rhs.value)
+    (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+    Add, (NoisyFloat<F, C>, NoisyFloat<F, C2>),
+    add(self, rhs: NoisyFloat<F, C2>) -> NoisyFloat<F, J> {
+        NoisyFloat::new_from_operation(self.value.add(rhs.value), "+", &[self.value, rhs.value])
     }
 );
 
 op_impl!(
     (F: Float, C: FloatChecker<F>), Sub, (NoisyFloat<F, C>, F),
     sub(self, rhs: F) -> NoisyFloat<F, C> {
-        NoisyFloat::new(self.value.sub(rhs))
+        NoisyFloat::new_from_operation(self.value.sub(rhs), "-", &[self.value, rhs])
     }
 );
 op_impl!(
-    (F: Float, C: FloatChecker<F>), Sub, (NoisyFloat<F, C>, NoisyFloat<F, C>),
-    sub(self, rhs: NoisyFloat<F, C>) -> NoisyFloat<F, C> {
-        self.sub(rhs.value)
+    (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+    Sub, (NoisyFloat<F, C>, NoisyFloat<F, C2>),
+    sub(self, rhs: NoisyFloat<F, C2>) -> NoisyFloat<F, J> {
+        NoisyFloat::new_from_operation(self.value.sub(rhs.value), "-", &[self.value, rhs.value])
     }
 );
 
 op_impl!(
     (F: Float, C: FloatChecker<F>), Mul, (NoisyFloat<F, C>, F),
     mul(self, rhs: F) -> NoisyFloat<F, C> {
-        NoisyFloat::new(self.value.mul(rhs))
+        NoisyFloat::new_from_operation(self.value.mul(rhs), "*", &[self.value, rhs])
     }
 );
 op_impl!(
-    (F: Float, C: FloatChecker<F>), Mul, (NoisyFloat<F, C>, NoisyFloat<F, C>),
-    mul(self, rhs: NoisyFloat<F, C>) -> NoisyFloat<F, C> {
-        self.mul(rhs.value)
+    (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+    Mul, (NoisyFloat<F, C>, NoisyFloat<F, C2>),
+    mul(self, rhs: NoisyFloat<F, C2>) -> NoisyFloat<F, J> {
+        NoisyFloat::new_from_operation(self.value.mul(rhs.value), "*", &[self.value, rhs.value])
     }
 );
 
 op_impl!(
     (F: Float, C: FloatChecker<F>), Div, (NoisyFloat<F, C>, F),
     div(self, rhs: F) -> NoisyFloat<F, C> {
-        NoisyFloat::new(self.value.div(rhs))
+        NoisyFloat::new_from_operation(self.value.div(rhs), "/", &[self.value, rhs])
     }
 );
 op_impl!(
-    (F: Float, C: FloatChecker<F>), Div, (NoisyFloat<F, C>, NoisyFloat<F, C>),
-    div(self, rhs: NoisyFloat<F, C>) -> NoisyFloat<F, C> {
-        self.div(rhs.value)
+    (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+    Div, (NoisyFloat<F, C>, NoisyFloat<F, C2>),
+    div(self, rhs: NoisyFloat<F, C2>) -> NoisyFloat<F, J> {
+        NoisyFloat::new_from_operation(self.value.div(rhs.value), "/", &[self.value, rhs.value])
     }
 );
 
 op_impl!(
     (F: Float, C: FloatChecker<F>), Rem, (NoisyFloat<F, C>, F),
     rem(self, rhs: F) -> NoisyFloat<F, C> {
-        NoisyFloat::new(self.value.rem(rhs))
+        NoisyFloat::new_from_operation(self.value.rem(rhs), "%", &[self.value, rhs])
+    }
+);
+op_impl!(
+    (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+    Rem, (NoisyFloat<F, C>, NoisyFloat<F, C2>),
+    rem(self, rhs: NoisyFloat<F, C2>) -> NoisyFloat<F, J> {
+        NoisyFloat::new_from_operation(self.value.rem(rhs.value), "%", &[self.value, rhs.value])
+    }
+);
+
+// `UnitChecker` does not join with itself, since only products stay in the unit interval.
+op_impl!(
+    (F: Float), Add, (NoisyFloat<F, UnitChecker>, NoisyFloat<F, UnitChecker>),
+    add(self, rhs: NoisyFloat<F, UnitChecker>) -> NoisyFloat<F, FiniteChecker> {
+        NoisyFloat::new_from_operation(self.value.add(rhs.value), "+", &[self.value, rhs.value])
+    }
+);
+op_impl!(
+    (F: Float), Sub, (NoisyFloat<F, UnitChecker>, NoisyFloat<F, UnitChecker>),
+    sub(self, rhs: NoisyFloat<F, UnitChecker>) -> NoisyFloat<F, FiniteChecker> {
+        NoisyFloat::new_from_operation(self.value.sub(rhs.value), "-", &[self.value, rhs.value])
+    }
+);
+op_impl!(
+    (F: Float), Mul, (NoisyFloat<F, UnitChecker>, NoisyFloat<F, UnitChecker>),
+    mul(self, rhs: NoisyFloat<F, UnitChecker>) -> NoisyFloat<F, UnitChecker> {
+        NoisyFloat::unchecked_new_generic(self.value.mul(rhs.value))
+    }
+);
+op_impl!(
+    (F: Float), Div, (NoisyFloat<F, UnitChecker>, NoisyFloat<F, UnitChecker>),
+    div(self, rhs: NoisyFloat<F, UnitChecker>) -> NoisyFloat<F, FiniteChecker> {
+        NoisyFloat::new_from_operation(self.value.div(rhs.value), "/", &[self.value, rhs.value])
     }
 );
 op_impl!(
-    (F: Float, C: FloatChecker<F>), Rem, (NoisyFloat<F, C>, NoisyFloat<F, C>),
-    rem(self, rhs: NoisyFloat<F, C>) -> NoisyFloat<F, C> {
-        self.rem(rhs.value)
+    (F: Float), Rem, (NoisyFloat<F, UnitChecker>, NoisyFloat<F, UnitChecker>),
+    rem(self, rhs: NoisyFloat<F, UnitChecker>) -> NoisyFloat<F, UnitChecker> {
+        NoisyFloat::new_from_operation(self.value.rem(rhs.value), "%", &[self.value, rhs.value])
     }
 );
 
@@ -259,31 +303,46 @@ macro_rules! float_left_op_impls {
         op_impl!(
             (C: FloatChecker<$ftp>), Add, ($ftp, NoisyFloat<$ftp, C>),
             add(self, rhs: NoisyFloat<$ftp, C>) -> NoisyFloat<$ftp, C> {
-                NoisyFloat::new(self.add(rhs.value))
+                // `self` is either a float or a reference to one.
+                let lhs: &$ftp = &self;
+                let lhs = *lhs;
+                NoisyFloat::new_from_operation(lhs.add(rhs.value), "+", &[lhs, rhs.value])
             }
         );
         op_impl!(
             (C: FloatChecker<$ftp>), Sub, ($ftp, NoisyFloat<$ftp, C>),
             sub(self, rhs: NoisyFloat<$ftp, C>) -> NoisyFloat<$ftp, C> {
-                NoisyFloat::new(self.sub(rhs.value))
+                // `self` is either a float or a reference to one.
+                let lhs: &$ftp = &self;
+                let lhs = *lhs;
+                NoisyFloat::new_from_operation(lhs.sub(rhs.value), "-", &[lhs, rhs.value])
             }
         );
         op_impl!(
             (C: FloatChecker<$ftp>), Mul, ($ftp, NoisyFloat<$ftp, C>),
             mul(self, rhs: NoisyFloat<$ftp, C>) -> NoisyFloat<$ftp, C> {
-                NoisyFloat::new(self.mul(rhs.value))
+                // `self` is either a float or a reference to one.
+                let lhs: &$ftp = &self;
+                let lhs = *lhs;
+                NoisyFloat::new_from_operation(lhs.mul(rhs.value), "*", &[lhs, rhs.value])
             }
         );
         op_impl!(
             (C: FloatChecker<$ftp>), Div, ($ftp, NoisyFloat<$ftp, C>),
             div(self, rhs: NoisyFloat<$ftp, C>) -> NoisyFloat<$ftp, C> {
-                NoisyFloat::new(self.div(rhs.value))
+                // `self` is either a float or a reference to one.
+                let lhs: &$ftp = &self;
+                let lhs = *lhs;
+                NoisyFloat::new_from_operation(lhs.div(rhs.value), "/", &[lhs, rhs.value])
             }
         );
         op_impl!(
             (C: FloatChecker<$ftp>), Rem, ($ftp, NoisyFloat<$ftp, C>),
             rem(self, rhs: NoisyFloat<$ftp, C>) -> NoisyFloat<$ftp, C> {
-                NoisyFloat::new(self.rem(rhs.value))
+                // `self` is either a float or a reference to one.
+                let lhs: &$ftp = &self;
+                let lhs = *lhs;
+                NoisyFloat::new_from_operation(lhs.rem(rhs.value), "%", &[lhs, rhs.value])
             }
         );
     };
@@ -291,12 +350,78 @@ macro_rules! float_left_op_impls {
 float_left_op_impls!(f32);
 float_left_op_impls!(f64);
 
+// Integers are only supported where the conversion to the float type is lossless,
+// as listed in the crate documentation.
+macro_rules! int_op_impls {
+    ($ftp:ty, $itp:ty) => {
+        impl<C: FloatChecker<$ftp>> PartialEq<$itp> for NoisyFloat<$ftp, C> {
+            #[inline]
+            fn eq(&self, other: &$itp) -> bool {
+                self.value == <$ftp as From<$itp>>::from(*other)
+            }
+        }
+        impl<C: FloatChecker<$ftp>> PartialEq<NoisyFloat<$ftp, C>> for $itp {
+            #[inline]
+            fn eq(&self, other: &NoisyFloat<$ftp, C>) -> bool {
+                <$ftp as From<$itp>>::from(*self) == other.value
+            }
+        }
+        impl<C: FloatChecker<$ftp>> PartialOrd<$itp> for NoisyFloat<$ftp, C> {
+            #[inline]
+            fn partial_cmp(&self, other: &$itp) -> Option<Ordering> {
+                self.value.partial_cmp(&<$ftp as From<$itp>>::from(*other))
+            }
+        }
+        impl<C: FloatChecker<$ftp>> PartialOrd<NoisyFloat<$ftp, C>> for $itp {
+            #[inline]
+            fn partial_cmp(&self, other: &NoisyFloat<$ftp, C>) -> Option<Ordering> {
+                <$ftp as From<$itp>>::from(*self).partial_cmp(&other.value)
+            }
+        }
+
+        int_op_impls!(@op $ftp, $itp, Add, add, "+");
+        int_op_impls!(@op $ftp, $itp, Sub, sub, "-");
+        int_op_impls!(@op $ftp, $itp, Mul, mul, "*");
+        int_op_impls!(@op $ftp, $itp, Div, div, "/");
+        int_op_impls!(@op $ftp, $itp, Rem, rem, "%");
+    };
+    (@op $ftp:ty, $itp:ty, $trid:ident, $fnid:ident, $symbol:literal) => {
+        op_impl!(
+            (C: FloatChecker<$ftp>), $trid, (NoisyFloat<$ftp, C>, $itp),
+            $fnid(self, rhs: $itp) -> NoisyFloat<$ftp, C> {
+                let rhs = <$ftp as From<$itp>>::from(rhs);
+                NoisyFloat::new_from_operation(self.value.$fnid(rhs), $symbol, &[self.value, rhs])
+            }
+        );
+        op_impl!(
+            (C: FloatChecker<$ftp>), $trid, ($itp, NoisyFloat<$ftp, C>),
+            $fnid(self, rhs: NoisyFloat<$ftp, C>) -> NoisyFloat<$ftp, C> {
+                // `self` is either an integer or a reference to one.
+                let lhs: &$itp = &self;
+                let lhs = <$ftp as From<$itp>>::from(*lhs);
+                NoisyFloat::new_from_operation(lhs.$fnid(rhs.value), $symbol, &[lhs, rhs.value])
+            }
+        );
+    };
+}
+int_op_impls!(f32, i8);
+int_op_impls!(f32, i16);
+int_op_impls!(f32, u8);
+int_op_impls!(f32, u16);
+int_op_impls!(f64, i8);
+int_op_impls!(f64, i16);
+int_op_impls!(f64, i32);
+int_op_impls!(f64, u8);
+int_op_impls!(f64, u16);
+int_op_impls!(f64, u32);
+
 impl<F: Float + AddAssign, C: FloatChecker<F>> AddAssign<F> for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn add_assign(&mut self, rhs: F) {
-        self.value.add_assign(rhs);
-        C::assert(self.value);
+        let mut value = self.value;
+        value.add_assign(rhs);
+        *self = Self::new_from_operation(value, "+", &[self.value, rhs]);
     }
 }
 
@@ -304,23 +429,26 @@ impl<'a, F: Float + AddAssign, C: FloatChecker<F>> AddAssign<&'a F> for NoisyFlo
     #[track_caller]
     #[inline]
     fn add_assign(&mut self, rhs: &'a F) {
-        self.value.add_assign(*rhs);
-        C::assert(self.value);
+        self.add_assign(*rhs);
     }
 }
 
-impl<F: Float + AddAssign, C: FloatChecker<F>> AddAssign for NoisyFloat<F, C> {
+impl<F: Float + AddAssign, C: FloatChecker<F>, C2: FloatChecker<F>> AddAssign<NoisyFloat<F, C2>>
+    for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn add_assign(&mut self, rhs: Self) {
+    fn add_assign(&mut self, rhs: NoisyFloat<F, C2>) {
         self.add_assign(rhs.value);
     }
 }
 
-impl<'a, F: Float + AddAssign, C: FloatChecker<F>> AddAssign<&'a Self> for NoisyFloat<F, C> {
+impl<'a, F: Float + AddAssign, C: FloatChecker<F>, C2: FloatChecker<F>>
+    AddAssign<&'a NoisyFloat<F, C2>> for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn add_assign(&mut self, rhs: &'a Self) {
+    fn add_assign(&mut self, rhs: &'a NoisyFloat<F, C2>) {
         self.add_assign(rhs.value);
     }
 }
@@ -329,8 +457,9 @@ impl<F: Float + SubAssign, C: FloatChecker<F>> SubAssign<F> for NoisyFloat<F, C>
     #[track_caller]
     #[inline]
     fn sub_assign(&mut self, rhs: F) {
-        self.value.sub_assign(rhs);
-        C::assert(self.value);
+        let mut value = self.value;
+        value.sub_assign(rhs);
+        *self = Self::new_from_operation(value, "-", &[self.value, rhs]);
     }
 }
 
@@ -338,23 +467,26 @@ impl<'a, F: Float + SubAssign, C: FloatChecker<F>> SubAssign<&'a F> for NoisyFlo
     #[track_caller]
     #[inline]
     fn sub_assign(&mut self, rhs: &'a F) {
-        self.value.sub_assign(*rhs);
-        C::assert(self.value);
+        self.sub_assign(*rhs);
     }
 }
 
-impl<F: Float + SubAssign, C: FloatChecker<F>> SubAssign for NoisyFloat<F, C> {
+impl<F: Float + SubAssign, C: FloatChecker<F>, C2: FloatChecker<F>> SubAssign<NoisyFloat<F, C2>>
+    for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) {
+    fn sub_assign(&mut self, rhs: NoisyFloat<F, C2>) {
         self.sub_assign(rhs.value);
     }
 }
 
-impl<'a, F: Float + SubAssign, C: FloatChecker<F>> SubAssign<&'a Self> for NoisyFloat<F, C> {
+impl<'a, F: Float + SubAssign, C: FloatChecker<F>, C2: FloatChecker<F>>
+    SubAssign<&'a NoisyFloat<F, C2>> for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn sub_assign(&mut self, rhs: &'a Self) {
+    fn sub_assign(&mut self, rhs: &'a NoisyFloat<F, C2>) {
         self.sub_assign(rhs.value);
     }
 }
@@ -363,8 +495,9 @@ impl<F: Float + MulAssign, C: FloatChecker<F>> MulAssign<F> for NoisyFloat<F, C>
     #[track_caller]
     #[inline]
     fn mul_assign(&mut self, rhs: F) {
-        self.value.mul_assign(rhs);
-        C::assert(self.value);
+        let mut value = self.value;
+        value.mul_assign(rhs);
+        *self = Self::new_from_operation(value, "*", &[self.value, rhs]);
     }
 }
 
@@ -372,23 +505,26 @@ impl<'a, F: Float + MulAssign, C: FloatChecker<F>> MulAssign<&'a F> for NoisyFlo
     #[track_caller]
     #[inline]
     fn mul_assign(&mut self, rhs: &'a F) {
-        self.value.mul_assign(*rhs);
-        C::assert(self.value);
+        self.mul_assign(*rhs);
     }
 }
 
-impl<F: Float + MulAssign, C: FloatChecker<F>> MulAssign for NoisyFloat<F, C> {
+impl<F: Float + MulAssign, C: FloatChecker<F>, C2: FloatChecker<F>> MulAssign<NoisyFloat<F, C2>>
+    for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn mul_assign(&mut self, rhs: Self) {
+    fn mul_assign(&mut self, rhs: NoisyFloat<F, C2>) {
         self.mul_assign(rhs.value);
     }
 }
 
-impl<'a, F: Float + MulAssign, C: FloatChecker<F>> MulAssign<&'a Self> for NoisyFloat<F, C> {
+impl<'a, F: Float + MulAssign, C: FloatChecker<F>, C2: FloatChecker<F>>
+    MulAssign<&'a NoisyFloat<F, C2>> for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn mul_assign(&mut self, rhs: &'a Self) {
+    fn mul_assign(&mut self, rhs: &'a NoisyFloat<F, C2>) {
         self.mul_assign(rhs.value);
     }
 }
@@ -397,8 +533,9 @@ impl<F: Float + DivAssign, C: FloatChecker<F>> DivAssign<F> for NoisyFloat<F, C>
     #[track_caller]
     #[inline]
     fn div_assign(&mut self, rhs: F) {
-        self.value.div_assign(rhs);
-        C::assert(self.value);
+        let mut value = self.value;
+        value.div_assign(rhs);
+        *self = Self::new_from_operation(value, "/", &[self.value, rhs]);
     }
 }
 
@@ -406,23 +543,26 @@ impl<'a, F: Float + DivAssign, C: FloatChecker<F>> DivAssign<&'a F> for NoisyFlo
     #[track_caller]
     #[inline]
     fn div_assign(&mut self, rhs: &'a F) {
-        self.value.div_assign(*rhs);
-        C::assert(self.value);
+        self.div_assign(*rhs);
     }
 }
 
-impl<F: Float + DivAssign, C: FloatChecker<F>> DivAssign for NoisyFloat<F, C> {
+impl<F: Float + DivAssign, C: FloatChecker<F>, C2: FloatChecker<F>> DivAssign<NoisyFloat<F, C2>>
+    for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn div_assign(&mut self, rhs: Self) {
+    fn div_assign(&mut self, rhs: NoisyFloat<F, C2>) {
         self.div_assign(rhs.value);
     }
 }
 
-impl<'a, F: Float + DivAssign, C: FloatChecker<F>> DivAssign<&'a Self> for NoisyFloat<F, C> {
+impl<'a, F: Float + DivAssign, C: FloatChecker<F>, C2: FloatChecker<F>>
+    DivAssign<&'a NoisyFloat<F, C2>> for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn div_assign(&mut self, rhs: &'a Self) {
+    fn div_assign(&mut self, rhs: &'a NoisyFloat<F, C2>) {
         self.div_assign(rhs.value);
     }
 }
@@ -431,8 +571,9 @@ impl<F: Float + RemAssign, C: FloatChecker<F>> RemAssign<F> for NoisyFloat<F, C>
     #[track_caller]
     #[inline]
     fn rem_assign(&mut self, rhs: F) {
-        self.value.rem_assign(rhs);
-        C::assert(self.value);
+        let mut value = self.value;
+        value.rem_assign(rhs);
+        *self = Self::new_from_operation(value, "%", &[self.value, rhs]);
     }
 }
 
@@ -440,23 +581,26 @@ impl<'a, F: Float + RemAssign, C: FloatChecker<F>> RemAssign<&'a F> for NoisyFlo
     #[track_caller]
     #[inline]
     fn rem_assign(&mut self, rhs: &'a F) {
-        self.value.rem_assign(*rhs);
-        C::assert(self.value);
+        self.rem_assign(*rhs);
     }
 }
 
-impl<F: Float + RemAssign, C: FloatChecker<F>> RemAssign for NoisyFloat<F, C> {
+impl<F: Float + RemAssign, C: FloatChecker<F>, C2: FloatChecker<F>> RemAssign<NoisyFloat<F, C2>>
+    for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn rem_assign(&mut self, rhs: Self) {
+    fn rem_assign(&mut self, rhs: NoisyFloat<F, C2>) {
         self.rem_assign(rhs.value);
     }
 }
 
-impl<'a, F: Float + RemAssign, C: FloatChecker<F>> RemAssign<&'a Self> for NoisyFloat<F, C> {
+impl<'a, F: Float + RemAssign, C: FloatChecker<F>, C2: FloatChecker<F>>
+    RemAssign<&'a NoisyFloat<F, C2>> for NoisyFloat<F, C>
+{
     #[track_caller]
     #[inline]
-    fn rem_assign(&mut self, rhs: &'a Self) {
+    fn rem_assign(&mut self, rhs: &'a NoisyFloat<F, C2>) {
         self.rem_assign(rhs.value);
     }
 }
@@ -466,11 +610,11 @@ impl<F: Float, C: FloatChecker<F>> Neg for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn neg(self) -> Self {
-        Self::new(self.value.neg())
+        Self::new_from_operation(self.value.neg(), "-", &[self.value])
     }
 }
 
-impl<'a, F: Float, C: FloatChecker<F>> Neg for &'a NoisyFloat<F, C> {
+impl<F: Float, C: FloatChecker<F>> Neg for &NoisyFloat<F, C> {
     type Output = NoisyFloat<F, C>;
     #[track_caller]
     #[inline]
@@ -479,9 +623,10 @@ impl<'a, F: Float, C: FloatChecker<F>> Neg for &'a NoisyFloat<F, C> {
     }
 }
 
-impl<F: Float, C: FloatChecker<F>> Zero for NoisyFloat<F, C> {
+impl<F: Float, C: FloatChecker<F> + Join<C, Output = C>> Zero for NoisyFloat<F, C> {
     #[inline]
     fn zero() -> Self {
+        let () = Admits::<F, C>::ZERO;
         Self::new(F::zero())
     }
     #[inline]
@@ -490,19 +635,29 @@ impl<F: Float, C: FloatChecker<F>> Zero for NoisyFloat<F, C> {
     }
 }
 
-impl<F: Float, C: FloatChecker<F>> One for NoisyFloat<F, C> {
+impl<F: Float, C: FloatChecker<F> + Join<C, Output = C>> One for NoisyFloat<F, C> {
     #[inline]
     fn one() -> Self {
+        let () = Admits::<F, C>::ONE;
         Self::new(F::one())
     }
 }
 
-impl<F: Float, C: FloatChecker<F>> Num for NoisyFloat<F, C> {
-    type FromStrRadixErr = F::FromStrRadixErr;
-    #[track_caller]
+impl<F: Float, C: FloatChecker<F> + Join<C, Output = C>> Num for NoisyFloat<F, C> {
+    type FromStrRadixErr = ParseError<F::FromStrRadixErr, F>;
     #[inline]
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
-        F::from_str_radix(str, radix).map(|v| Self::new(v))
+        let value = F::from_str_radix(str, radix).map_err(ParseError::Syntax)?;
+        Self::try_new_checked(value).map_err(ParseError::Invalid)
+    }
+}
+
+impl<F: Float + FromStr, C: FloatChecker<F>> FromStr for NoisyFloat<F, C> {
+    type Err = ParseError<F::Err, F>;
+    #[inline]
+    fn from_str(str: &str) -> Result<Self, Self::Err> {
+        let value = str.parse().map_err(ParseError::Syntax)?;
+        Self::try_new_checked(value).map_err(ParseError::Invalid)
     }
 }
 
@@ -645,18 +800,18 @@ impl<C: FloatChecker<f32>> From<NoisyFloat<f32, C>> for f64 {
 }
 
 impl<C: FloatChecker<f64>> TryFrom<f64> for NoisyFloat<f64, C> {
-    type Error = &'static str;
+    type Error = InvalidFloat<f64>;
     #[inline]
     fn try_from(f: f64) -> Result<Self, Self::Error> {
-        Self::try_new(f).ok_or("illegal value")
+        Self::try_new_checked(f)
     }
 }
 
 impl<C: FloatChecker<f32>> TryFrom<f32> for NoisyFloat<f32, C> {
-    type Error = &'static str;
+    type Error = InvalidFloat<f32>;
     #[inline]
     fn try_from(f: f32) -> Result<Self, Self::Error> {
-        Self::try_new(f).ok_or("illegal value")
+        Self::try_new_checked(f)
     }
 }
 
@@ -681,7 +836,7 @@ from_primitive!(i8, f64);
 from_primitive!(i16, f64);
 from_primitive!(i32, f64);
 
-impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
+impl<F: Float, C: FloatChecker<F> + Join<C, Output = C>> Float for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn nan() -> Self {
@@ -745,37 +900,37 @@ impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn floor(self) -> Self {
-        Self::new(self.value.floor())
+        Self::new_from_operation(self.value.floor(), "floor", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn ceil(self) -> Self {
-        Self::new(self.value.ceil())
+        Self::new_from_operation(self.value.ceil(), "ceil", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn round(self) -> Self {
-        Self::new(self.value.round())
+        Self::new_from_operation(self.value.round(), "round", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn trunc(self) -> Self {
-        Self::new(self.value.trunc())
+        Self::new_from_operation(self.value.trunc(), "trunc", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn fract(self) -> Self {
-        Self::new(self.value.fract())
+        Self::new_from_operation(self.value.fract(), "fract", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn abs(self) -> Self {
-        Self::new(self.value.abs())
+        Self::new_from_operation(self.value.abs(), "abs", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn signum(self) -> Self {
-        Self::new(self.value.signum())
+        Self::new_from_operation(self.value.signum(), "signum", &[self.value])
     }
     #[track_caller]
     #[inline]
@@ -790,163 +945,194 @@ impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn mul_add(self, a: Self, b: Self) -> Self {
-        Self::new(self.value.mul_add(a.value, b.value))
+        Self::new_from_operation(
+            self.value.mul_add(a.value, b.value),
+            "mul_add",
+            &[self.value, a.value, b.value],
+        )
     }
     #[track_caller]
     #[inline]
     fn recip(self) -> Self {
-        Self::new(self.value.recip())
+        Self::new_from_operation(self.value.recip(), "recip", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn powi(self, n: i32) -> Self {
-        Self::new(self.value.powi(n))
+        Self::new_from_operation(
+            self.value.powi(n),
+            "powi",
+            &[self.value, F::from(n).unwrap_or_else(F::nan)],
+        )
     }
     #[track_caller]
     #[inline]
     fn powf(self, n: Self) -> Self {
-        Self::new(self.value.powf(n.value))
+        Self::new_from_operation(self.value.powf(n.value), "powf", &[self.value, n.value])
     }
     #[track_caller]
     #[inline]
     fn sqrt(self) -> Self {
-        Self::new(self.value.sqrt())
+        Self::new_from_operation(self.value.sqrt(), "sqrt", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn exp(self) -> Self {
-        Self::new(self.value.exp())
+        Self::new_from_operation(self.value.exp(), "exp", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn exp2(self) -> Self {
-        Self::new(self.value.exp2())
+        Self::new_from_operation(self.value.exp2(), "exp2", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn ln(self) -> Self {
-        Self::new(self.value.ln())
+        Self::new_from_operation(self.value.ln(), "ln", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn log(self, base: Self) -> Self {
-        Self::new(self.value.log(base.value))
+        Self::new_from_operation(self.value.log(base.value), "log", &[self.value, base.value])
     }
     #[track_caller]
     #[inline]
     fn log2(self) -> Self {
-        Self::new(self.value.log2())
+        Self::new_from_operation(self.value.log2(), "log2", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn log10(self) -> Self {
-        Self::new(self.value.log10())
+        Self::new_from_operation(self.value.log10(), "log10", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn max(self, other: Self) -> Self {
-        Self::new(self.value.max(other.value))
+        Self::new_from_operation(
+            self.value.max(other.value),
+            "max",
+            &[self.value, other.value],
+        )
     }
     #[track_caller]
     #[inline]
     fn min(self, other: Self) -> Self {
-        Self::new(self.value.min(other.value))
+        Self::new_from_operation(
+            self.value.min(other.value),
+            "min",
+            &[self.value, other.value],
+        )
     }
     #[track_caller]
     #[inline]
     fn abs_sub(self, other: Self) -> Self {
-        Self::new(self.value.abs_sub(other.value))
+        Self::new_from_operation(
+            self.value.abs_sub(other.value),
+            "abs_sub",
+            &[self.value, other.value],
+        )
     }
     #[track_caller]
     #[inline]
     fn cbrt(self) -> Self {
-        Self::new(self.value.cbrt())
+        Self::new_from_operation(self.value.cbrt(), "cbrt", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn hypot(self, other: Self) -> Self {
-        Self::new(self.value.hypot(other.value))
+        Self::new_from_operation(
+            self.value.hypot(other.value),
+            "hypot",
+            &[self.value, other.value],
+        )
     }
     #[track_caller]
     #[inline]
     fn sin(self) -> Self {
-        Self::new(self.value.sin())
+        Self::new_from_operation(self.value.sin(), "sin", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn cos(self) -> Self {
-        Self::new(self.value.cos())
+        Self::new_from_operation(self.value.cos(), "cos", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn tan(self) -> Self {
-        Self::new(self.value.tan())
+        Self::new_from_operation(self.value.tan(), "tan", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn asin(self) -> Self {
-        Self::new(self.value.asin())
+        Self::new_from_operation(self.value.asin(), "asin", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn acos(self) -> Self {
-        Self::new(self.value.acos())
+        Self::new_from_operation(self.value.acos(), "acos", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn atan(self) -> Self {
-        Self::new(self.value.atan())
+        Self::new_from_operation(self.value.atan(), "atan", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn atan2(self, other: Self) -> Self {
-        Self::new(self.value.atan2(other.value))
+        Self::new_from_operation(
+            self.value.atan2(other.value),
+            "atan2",
+            &[self.value, other.value],
+        )
     }
     #[track_caller]
     #[inline]
     fn sin_cos(self) -> (Self, Self) {
         let (a, b) = self.value.sin_cos();
-        (Self::new(a), Self::new(b))
+        (
+            Self::new_from_operation(a, "sin_cos", &[self.value]),
+            Self::new_from_operation(b, "sin_cos", &[self.value]),
+        )
     }
     #[track_caller]
     #[inline]
     fn exp_m1(self) -> Self {
-        Self::new(self.value.exp_m1())
+        Self::new_from_operation(self.value.exp_m1(), "exp_m1", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn ln_1p(self) -> Self {
-        Self::new(self.value.ln_1p())
+        Self::new_from_operation(self.value.ln_1p(), "ln_1p", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn sinh(self) -> Self {
-        Self::new(self.value.sinh())
+        Self::new_from_operation(self.value.sinh(), "sinh", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn cosh(self) -> Self {
-        Self::new(self.value.cosh())
+        Self::new_from_operation(self.value.cosh(), "cosh", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn tanh(self) -> Self {
-        Self::new(self.value.tanh())
+        Self::new_from_operation(self.value.tanh(), "tanh", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn asinh(self) -> Self {
-        Self::new(self.value.asinh())
+        Self::new_from_operation(self.value.asinh(), "asinh", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn acosh(self) -> Self {
-        Self::new(self.value.acosh())
+        Self::new_from_operation(self.value.acosh(), "acosh", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn atanh(self) -> Self {
-        Self::new(self.value.atanh())
+        Self::new_from_operation(self.value.atanh(), "atanh", &[self.value])
     }
     #[track_caller]
     #[inline]
@@ -961,12 +1147,12 @@ impl<F: Float, C: FloatChecker<F>> Float for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn to_degrees(self) -> Self {
-        Self::new(self.value.to_degrees())
+        Self::new_from_operation(self.value.to_degrees(), "to_degrees", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn to_radians(self) -> Self {
-        Self::new(self.value.to_radians())
+        Self::new_from_operation(self.value.to_radians(), "to_radians", &[self.value])
     }
 }
 
@@ -1037,21 +1223,25 @@ impl<F: Float + FloatConst, C: FloatChecker<F>> FloatConst for NoisyFloat<F, C>
     }
 }
 
-impl<F: Float + Signed, C: FloatChecker<F>> Signed for NoisyFloat<F, C> {
+impl<F: Float + Signed, C: FloatChecker<F> + Join<C, Output = C>> Signed for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn abs(&self) -> Self {
-        Self::new(self.value.abs())
+        Self::new_from_operation(self.value.abs(), "abs", &[self.value])
     }
     #[track_caller]
     #[inline]
     fn abs_sub(&self, other: &Self) -> Self {
-        Self::new(self.value.abs_sub(other.value))
+        Self::new_from_operation(
+            self.value.abs_sub(other.value),
+            "abs_sub",
+            &[self.value, other.value],
+        )
     }
     #[track_caller]
     #[inline]
     fn signum(&self) -> Self {
-        Self::new(self.value.signum())
+        Self::new_from_operation(self.value.signum(), "signum", &[self.value])
     }
     #[track_caller]
     #[inline]
@@ -1069,11 +1259,13 @@ impl<F: Float + Bounded, C: FloatChecker<F>> Bounded for NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     fn min_value() -> Self {
+        let () = Admits::<F, C>::NEGATIVE;
         Self::new(Float::min_value())
     }
     #[track_caller]
     #[inline]
     fn max_value() -> Self {
+        let () = Admits::<F, C>::POSITIVE;
         Self::new(Float::max_value())
     }
 }
@@ -57,6 +57,18 @@
 //! assert!(mean(r64(10.0), r64(20.0)) == 15.0);
 //! ```
 //!
+//! Operands can also be integers that convert to the underlying float type losslessly:
+//! `i8`, `i16`, `u8` and `u16` for `f32`, and additionally `i32` and `u32` for `f64`.
+//! Other integers, such as an `i32` combined with an `R32`, must be converted explicitly.
+//!
+//! ```
+//! use noisy_float::prelude::*;
+//!
+//! assert!(r64(2.5) * 3i32 == 7.5);
+//! assert!(2u8 - r32(0.5) == 1.5);
+//! assert!(r32(2.5) * (3i32 as f32) == 7.5);
+//! ```
+//!
 //! An example using the `N32` type, which corresponds to *non-NaN* `f32` values.
 //! The float types in this crate are able to implement `Eq` and `Ord` properly,
 //! since NaN is not allowed.
@@ -69,6 +81,46 @@
 //! assert!(values.iter().cloned().max() == Some(N32::infinity()));
 //! ```
 //!
+//! Some math functions have result types that follow the mathematics.
+//! For example, the absolute value of an `R64` is a non-negative `NN64`,
+//! whose square root and logarithm are always defined.
+//!
+//! ```
+//! use noisy_float::prelude::*;
+//!
+//! fn distance(dx: R64, dy: R64) -> NN64 {
+//!     (dx.square() + dy.square()).sqrt()
+//! }
+//!
+//! assert_eq!(distance(r64(3.0), r64(-4.0)), 5.0);
+//! assert!(r64(-2.0).abs().ln() > 0.0);
+//!
+//! let (sin, cos): (SU64, SU64) = r64(0.5).sin_cos();
+//! assert!(sin < cos);
+//! ```
+//!
+//! These methods take precedence over the `Float` methods of the same name,
+//! so code that expects the type of the operand back no longer compiles as is.
+//! Convert the result with `widen`, or call the `Float` method explicitly.
+//! `powi` is not refined, since whether its result is non-negative depends on the parity
+//! of the exponent, which is only known at run time; `powi_even::<N>()` is.
+//!
+//! ```
+//! use noisy_float::prelude::*;
+//!
+//! fn magnitude(x: R64) -> R64 {
+//!     x.abs().widen()
+//! }
+//!
+//! fn magnitude_float(x: R64) -> R64 {
+//!     Float::abs(x)
+//! }
+//!
+//! assert_eq!(magnitude(r64(-2.0)), magnitude_float(r64(-2.0)));
+//! assert_eq!(r64(-2.0).powi(2), 4.0);
+//! assert_eq!(r64(-2.0).powi_even::<2>(), nn64(4.0));
+//! ```
+//!
 //! An example converting from R64 to primitive types.
 //!
 //! ```
@@ -92,17 +144,51 @@
 //!   will transparently serialize then as floats
 //! - `approx`: Adds implementations to use `NoisyFloat` with the `approx`
 //!   crate
+//! - `std`: Implements `std::error::Error` for `InvalidFloat`, and makes the
+//!   status word of the `flags` module thread-local
+//! - `log`, `tracing`: Add violation handlers that emit an event for each
+//!   *invalid* value, with the `log` and `tracing` crates
+//! - `profiling`: Counts constructions, checks and violations per call site,
+//!   reported by `stats::snapshot`, and adds the `RangeProfiler` checker;
+//!   implies `std`. Every construction and check then costs atomic increments,
+//!   and a lock at call sites beyond the first 256, so it is meant for diagnostic builds
+//! - `strict`: Checks the values of the standard types with `assert!` instead of
+//!   `debug_assert!`, so that they are also checked in optimized builds;
+//!   without it, the violation handler is never called for the standard types
+//!   in optimized builds
 
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[cfg(feature = "serde")]
 use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
+mod checked;
 pub mod checkers;
+mod error;
+pub mod flags;
+#[macro_use]
 mod float_impl;
 #[cfg(feature = "proptest")]
 pub mod proptest;
+mod refine;
+mod result;
+pub mod sampling;
+mod saturating;
+pub mod shadow;
+#[cfg(feature = "profiling")]
+pub mod stats;
 pub mod types;
+pub mod violation;
+
+pub use crate::{
+    error::{InvalidFloat, InvalidReason, Operation, ParseError},
+    result::NoisyResult,
+    saturating::Saturating,
+    shadow::Shadowed,
+};
 
 /// Prelude for the `noisy_float` crate.
 ///
@@ -117,13 +203,23 @@ pub mod prelude {
     pub use num_traits::Float;
 }
 
-use core::{fmt, marker::PhantomData};
+use crate::checkers::{
+    Admits, AdmitsInfinity, AdmitsNegative, AdmitsPositive, AdmitsZero, Capabilities, Clamp, Subset,
+};
+use core::{fmt, marker::PhantomData, num::FpCategory};
 use num_traits::Float;
 
 /// Trait for checking whether a floating point number is *valid*.
 ///
 /// The implementation defines its own criteria for what constitutes a *valid* value.
 pub trait FloatChecker<F> {
+    /// The constants that this checker considers *valid*.
+    ///
+    /// The constructors of `Zero`, `One` and `Bounded` fail to compile
+    /// for checkers that do not admit their constant.
+    /// The default admits all of them, leaving them to the runtime check.
+    const CAPABILITIES: Capabilities = Capabilities::ALL;
+
     /// Returns `true` if (and only if) the given floating point number is *valid*
     /// according to this checker's criteria.
     ///
@@ -133,8 +229,71 @@ pub trait FloatChecker<F> {
 
     /// A function that may panic if the floating point number is *invalid*.
     ///
-    /// Should either call `assert!(check(value), ...)` or `debug_assert!(check(value), ...)`.
+    /// Should either call `assert!(check(value), ...)` or `debug_assert!(check(value), ...)`,
+    /// or pass *invalid* values to `violation::report` to go through the violation handler.
     fn assert(value: F);
+
+    /// Returns a message describing why the given *invalid* value is rejected,
+    /// for use in panic messages.
+    ///
+    /// The default implementation returns a generic message.
+    #[inline]
+    fn message(_value: F) -> &'static str {
+        "invalid value"
+    }
+
+    /// Like `assert`, but returns the value to continue with,
+    /// which the violation handler may have replaced with `violation::Action::Replace`.
+    ///
+    /// The default implementation calls `assert` and returns `value`.
+    #[track_caller]
+    #[inline]
+    fn assert_or_replace(value: F) -> F
+    where
+        F: Float,
+    {
+        Self::assert(value);
+        value
+    }
+
+    /// Like `assert_or_replace`, for a value produced by the given `Operation`,
+    /// such as the result of an arithmetic operator.
+    ///
+    /// The default implementation calls `assert` and returns `value`, ignoring the operation.
+    #[track_caller]
+    #[inline]
+    fn assert_operation(value: F, operation: Operation<F>) -> F
+    where
+        F: Float,
+    {
+        let _ = operation;
+        Self::assert(value);
+        value
+    }
+
+    /// Returns the name of this checker, as reported to the violation handler.
+    ///
+    /// The default implementation returns the type name.
+    #[inline]
+    fn name() -> &'static str {
+        core::any::type_name::<Self>()
+    }
+
+    /// Returns the reason why the given floating point number is *invalid*,
+    /// or `Ok(())` if it is *valid*.
+    ///
+    /// Must agree with `check`. The default implementation uses `InvalidReason::classify`.
+    #[inline]
+    fn validate(value: F) -> Result<(), InvalidReason>
+    where
+        F: Float,
+    {
+        if Self::check(value) {
+            Ok(())
+        } else {
+            Err(InvalidReason::classify(value))
+        }
+    }
 }
 
 /// A floating point number with a restricted set of legal values.
@@ -164,8 +323,20 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     pub fn new(value: F) -> Self {
-        C::assert(value);
-        Self::unchecked_new_generic(value)
+        #[cfg(feature = "profiling")]
+        stats::record::<F, C>(stats::Event::Construction);
+        Self::unchecked_new_generic(C::assert_or_replace(value))
+    }
+
+    /// Constructs a `NoisyFloat` with the value produced by an operation on the operands.
+    ///
+    /// Uses the `FloatChecker` to assert that the value is valid.
+    #[track_caller]
+    #[inline]
+    pub(crate) fn new_from_operation(value: F, name: &'static str, operands: &[F]) -> Self {
+        #[cfg(feature = "profiling")]
+        stats::record::<F, C>(stats::Event::Operation);
+        Self::unchecked_new_generic(C::assert_operation(value, Operation::new(name, operands)))
     }
 
     #[inline]
@@ -176,6 +347,24 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
         }
     }
 
+    /// Constructs a `NoisyFloat` with the given value,
+    /// replacing a subnormal value by zero of the same sign.
+    ///
+    /// Uses the `FloatChecker` to assert that the resulting value is valid.
+    #[track_caller]
+    #[inline]
+    pub fn new_flush_to_zero(value: F) -> Self {
+        if value.classify() == FpCategory::Subnormal {
+            Self::new(if value.is_sign_negative() {
+                F::neg_zero()
+            } else {
+                F::zero()
+            })
+        } else {
+            Self::new(value)
+        }
+    }
+
     /// Tries to construct a `NoisyFloat` with the given value.
     ///
     /// Returns `None` if the value is invalid.
@@ -191,6 +380,18 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
         }
     }
 
+    /// Tries to construct a `NoisyFloat` with the given value.
+    ///
+    /// Returns an `InvalidFloat` with the reason given by the `FloatChecker`
+    /// if the value is invalid.
+    #[inline]
+    pub fn try_new_checked(value: F) -> Result<Self, InvalidFloat<F>> {
+        match C::validate(value) {
+            Ok(()) => Ok(Self::unchecked_new_generic(value)),
+            Err(reason) => Err(InvalidFloat::new(value, reason)),
+        }
+    }
+
     /// Converts the value in-place to a reference to a `NoisyFloat`.
     ///
     /// Uses the `FloatChecker` to assert that the value is valid.
@@ -226,7 +427,7 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
     #[track_caller]
     #[inline]
     pub fn borrowed_mut(value: &mut F) -> &mut Self {
-        C::assert(*value);
+        *value = C::assert_or_replace(*value);
         Self::unchecked_borrowed_mut(value)
     }
 
@@ -291,11 +492,87 @@ impl<F: Float, C: FloatChecker<F>> NoisyFloat<F, C> {
     pub fn max(self, other: Self) -> Self {
         Ord::max(self, other)
     }
+
+    /// Converts the value to a `NoisyFloat` with a checker that admits all values
+    /// of this checker, without checking.
+    #[inline]
+    pub fn widen<C2: FloatChecker<F>>(self) -> NoisyFloat<F, C2>
+    where
+        C: Subset<C2>,
+    {
+        NoisyFloat::unchecked_new_generic(self.value)
+    }
+
+    /// Tries to convert the value to a `NoisyFloat` with a checker whose valid values
+    /// are all valid for this checker.
+    ///
+    /// Returns `None` if the value is invalid for the narrower checker.
+    #[inline]
+    pub fn narrow<C2: FloatChecker<F> + Subset<C>>(self) -> Option<NoisyFloat<F, C2>> {
+        self.try_into_checker()
+    }
+
+    /// Tries to convert the value to a `NoisyFloat` with any other checker.
+    ///
+    /// Returns `None` if the value is invalid for that checker.
+    #[inline]
+    pub fn try_into_checker<C2: FloatChecker<F>>(self) -> Option<NoisyFloat<F, C2>> {
+        NoisyFloat::try_new(self.value)
+    }
+}
+
+impl<F: Float, C: Clamp<F>> NoisyFloat<F, C> {
+    /// Constructs a `NoisyFloat` with the valid value nearest to the given value,
+    /// as defined by the `Clamp` implementation of the checker.
+    ///
+    /// # Panics
+    /// Panics if the value is NaN, even in optimized builds, since NaN cannot be clamped.
+    #[track_caller]
+    #[inline]
+    pub fn new_clamped(value: F) -> Self {
+        assert!(!value.is_nan(), "cannot clamp NaN");
+        Self::new(C::clamp(value))
+    }
+}
+
+impl<F: Float, C: FloatChecker<F> + AdmitsZero> NoisyFloat<F, C> {
+    /// Returns negative zero.
+    ///
+    /// Only available if the checker admits zero.
+    #[track_caller]
+    #[inline]
+    pub fn neg_zero() -> Self {
+        let () = Admits::<F, C>::ZERO;
+        Self::new(F::neg_zero())
+    }
+}
+
+impl<F: Float, C: FloatChecker<F> + AdmitsPositive + AdmitsInfinity> NoisyFloat<F, C> {
+    /// Returns positive infinity.
+    ///
+    /// Only available if the checker admits positive values and infinity.
+    #[track_caller]
+    #[inline]
+    pub fn infinity() -> Self {
+        Self::new(F::infinity())
+    }
+}
+
+impl<F: Float, C: FloatChecker<F> + AdmitsNegative + AdmitsInfinity> NoisyFloat<F, C> {
+    /// Returns negative infinity.
+    ///
+    /// Only available if the checker admits negative values and infinity.
+    #[track_caller]
+    #[inline]
+    pub fn neg_infinity() -> Self {
+        Self::new(F::neg_infinity())
+    }
 }
 
-impl<F: Float + Default, C: FloatChecker<F>> Default for NoisyFloat<F, C> {
+impl<F: Float + Default, C: FloatChecker<F> + AdmitsZero> Default for NoisyFloat<F, C> {
     #[inline]
     fn default() -> Self {
+        let () = Admits::<F, C>::ZERO;
         Self::new(F::default())
     }
 }
@@ -339,20 +616,21 @@ impl<F: Float + Serialize, C: FloatChecker<F>> Serialize for NoisyFloat<F, C> {
 impl<'de, F: Float + Deserialize<'de>, C: FloatChecker<F>> Deserialize<'de> for NoisyFloat<F, C> {
     fn deserialize<D: Deserializer<'de>>(de: D) -> Result<Self, D::Error> {
         let value = F::deserialize(de)?;
-        Self::try_new(value).ok_or_else(|| D::Error::custom("invalid NoisyFloat"))
+        Self::try_new_checked(value).map_err(|error| {
+            D::Error::custom(format_args!("invalid NoisyFloat: {}", error.reason()))
+        })
     }
 }
 
 #[cfg(test)]
+#[allow(clippy::approx_constant)]
 mod tests {
     extern crate std;
-    use std::prelude::v1::*;
+    use std::vec::Vec;
 
     use crate::prelude::*;
     #[cfg(feature = "serde")]
     use serde_derive::{Deserialize, Serialize};
-    #[cfg(feature = "serde")]
-    use serde_json;
     use std::{
         f32,
         f64::{self, consts},
@@ -403,26 +681,575 @@ mod tests {
     }
 
     #[test]
-    #[cfg(debug_assertions)]
+    #[cfg(any(debug_assertions, feature = "strict"))]
     #[should_panic]
     fn n64_nan() {
         let _ = n64(0.0) / n64(0.0);
     }
 
     #[test]
-    #[cfg(debug_assertions)]
+    #[cfg(any(debug_assertions, feature = "strict"))]
     #[should_panic]
     fn r64_nan() {
         let _ = r64(0.0) / r64(0.0);
     }
 
     #[test]
-    #[cfg(debug_assertions)]
+    #[cfg(any(debug_assertions, feature = "strict"))]
     #[should_panic]
     fn r64_infinity() {
         let _ = r64(1.0) / r64(0.0);
     }
 
+    #[test]
+    fn sign_checkers() {
+        assert_eq!(p64(2.0) * 3.0, 6.0);
+        assert_eq!(P64::try_new(0.0), None);
+        assert_eq!(P64::try_new(f64::INFINITY), None);
+        assert_eq!(PX64::try_new(f64::INFINITY), Some(PX64::infinity()));
+        assert_eq!(NN32::try_new(-0.0), Some(nn32(0.0)));
+        assert_eq!(NN64::try_new(-1.0), None);
+        assert_eq!(ng64(-1.5).raw(), -1.5);
+        assert_eq!(NG64::try_new(-0.0), None);
+        assert_eq!(
+            NGX64::try_new(f64::NEG_INFINITY),
+            Some(NGX64::neg_infinity())
+        );
+        assert_eq!(NP64::try_new(0.0), Some(np64(-0.0)));
+        assert_eq!(NPX32::try_new(1.0), None);
+        assert_eq!(PX64::try_new(f64::NAN), None);
+        assert_eq!(NNX64::try_new(f64::NAN), None);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[should_panic]
+    fn nn64_negative() {
+        let _ = nn64(1.0) - nn64(2.0);
+    }
+
+    #[test]
+    fn range_checker() {
+        type Probability = crate::ranged!(f64, 0.0 <= p <= 1.0);
+        type Cosine = crate::ranged!(f32, -1 <= x <= 1);
+        type Open = crate::ranged!(f64, 0.0 < x < 0.5);
+
+        let half = Probability::new(0.5);
+        assert_eq!(half * half, 0.25);
+        assert_eq!(half.sqrt(), 0.5f64.sqrt());
+        assert!(half < Probability::new(0.75));
+        assert_eq!(hash_bytes(half), hash_bytes(0.5f64.to_bits()));
+        assert_eq!(Probability::try_new(1.0000001), None);
+        assert_eq!(Probability::try_new(f64::NAN), None);
+        assert_eq!(Cosine::try_new(-1.0), Some(Cosine::new(-1.0)));
+        assert_eq!(Cosine::try_new(1.5), None);
+        assert_eq!(Open::try_new(0.0), None);
+        assert_eq!(Open::try_new(0.5), None);
+        assert_eq!(Open::try_new(0.25).map(|x| x.raw()), Some(0.25));
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn unit_interval_strategy() {
+        use proptest::{
+            arbitrary::any,
+            strategy::{Strategy, ValueTree},
+            test_runner::TestRunner,
+        };
+
+        let mut runner = TestRunner::deterministic();
+        for _ in 0..100 {
+            let unit = any::<U64>().new_tree(&mut runner).unwrap().current();
+            let signed_unit = any::<SU32>().new_tree(&mut runner).unwrap().current();
+            assert!((0.0..=1.0).contains(&unit.raw()));
+            assert!((-1.0..=1.0).contains(&signed_unit.raw()));
+        }
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[should_panic]
+    fn range_overflow() {
+        type Probability = crate::ranged!(f64, 0.0 <= p <= 1.0);
+        let _ = Probability::new(0.5) + 0.75;
+    }
+
+    #[test]
+    fn unit_interval() {
+        let p = unit64(0.25);
+        let q = U64::new(0.5);
+        let product: U64 = p * q;
+        assert_eq!(product, 0.125);
+        assert_eq!(p.complement(), 0.75);
+        assert_eq!(p.min(q), p);
+        assert_eq!(p.max(q), q);
+        assert_eq!(q.powf(nn64(2.0)), 0.25);
+        assert_eq!(unit64(0.0).powf(nn64(0.0)), 1.0);
+
+        let sum: R64 = p + q;
+        let difference: R64 = p - q;
+        let ratio: R64 = q / p;
+        let doubled: R64 = p.widen() * 2.0;
+        let mixed: NN64 = p + nn64(2.0);
+        assert_eq!(sum, 0.75);
+        assert_eq!(difference, -0.25);
+        assert_eq!(ratio, 2.0);
+        assert_eq!(unit64(0.7) + unit64(0.6), 0.7 + 0.6);
+        assert_eq!(unit32(0.5) % unit32(0.375), 0.125);
+        assert_eq!(doubled, 0.5);
+        assert_eq!(mixed, 2.25);
+        assert_eq!("0.5".parse::<U64>().unwrap(), q);
+        assert!("1.5".parse::<U64>().is_err());
+        assert!(U64::try_new_checked(-0.5).is_err());
+
+        const HALF: U64 = U64::unchecked_new(0.5);
+        assert_eq!(HALF, q);
+
+        assert!(p < q);
+        assert_eq!(U32::try_new(1.5), None);
+        assert_eq!(U32::try_new(f32::NAN), None);
+        assert_eq!(hash_bytes(q), hash_bytes(0.5f64.to_bits()));
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[should_panic]
+    fn unit_out_of_range() {
+        let _ = unit32(1.25);
+    }
+
+    #[test]
+    fn non_zero_divisor() {
+        assert_eq!(r64(3.0) / nz64(2.0), r64(1.5));
+        assert_eq!(r64(3.0) % nz64(2.0), r64(1.0));
+        assert_eq!(r32(3.0) / NZX32::infinity(), r32(0.0));
+        let mut value = r64(9.0);
+        value /= nz64(-3.0);
+        value %= nz64(2.0);
+        assert_eq!(value, -1.0);
+        assert_eq!(NZ64::try_new(0.0), None);
+        assert_eq!(NZ64::try_new(-0.0), None);
+        assert_eq!(NZ64::try_new(f64::INFINITY), None);
+        assert_eq!(NZX64::try_new(f64::NAN), None);
+    }
+
+    #[test]
+    fn normal_checker() {
+        let tiny = f64::MIN_POSITIVE / 4.0;
+        assert_eq!(NR64::try_new(tiny), None);
+        assert_eq!(NR64::try_new(-0.0), Some(nr64(0.0)));
+        assert_eq!(
+            NR64::try_new(f64::MIN_POSITIVE),
+            Some(nr64(f64::MIN_POSITIVE))
+        );
+        assert_eq!(NR64::try_new(f64::INFINITY), None);
+        assert_eq!(NR32::try_new(f32::MIN_POSITIVE / 2.0), None);
+
+        let flushed = NR64::new_flush_to_zero(-tiny);
+        assert_eq!(flushed, 0.0);
+        assert!(flushed.is_sign_negative());
+        assert!(NR64::new_flush_to_zero(tiny).is_sign_positive());
+        assert_eq!(NR64::new_flush_to_zero(1.5), 1.5);
+        assert_eq!(N64::new_flush_to_zero(f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[should_panic]
+    fn nr64_subnormal() {
+        let _ = nr64(f64::MIN_POSITIVE) / 2.0;
+    }
+
+    #[test]
+    fn refined_math() {
+        let abs: NN64 = r64(-2.0).abs();
+        let square: NN64 = r64(-3.0).square();
+        let hypot: NN64 = r64(3.0).hypot(r64(-4.0));
+        let exp: NN64 = r64(0.0).exp();
+        let powi: NN64 = r64(-2.0).powi_even::<4>();
+        let powi_zero: NNX64 = n64(f64::NEG_INFINITY).powi_even::<0>();
+        let sin: SU64 = r64(consts::FRAC_PI_2).sin();
+        let cos: SU32 = r32(0.0).cos();
+        assert_eq!(abs, 2.0);
+        assert_eq!(square, 9.0);
+        assert_eq!(hypot, 5.0);
+        assert_eq!(exp, 1.0);
+        assert_eq!(powi, 16.0);
+        assert_eq!(powi_zero, 1.0);
+        assert_eq!(r64(-1000.0).exp(), 0.0);
+        assert_eq!(sin, 1.0);
+        assert_eq!(cos, 1.0);
+
+        let abs: NNX64 = N64::neg_infinity().abs();
+        let exp: NNX64 = n64(1000.0).exp();
+        assert_eq!(abs, f64::INFINITY);
+        assert_eq!(exp, f64::INFINITY);
+
+        let sqrt: NN64 = nn64(9.0).sqrt();
+        let ln: N64 = nn64(0.0).ln();
+        let log2: R64 = p64(0.5).log2();
+        let log10: N64 = PX64::infinity().log10();
+        assert_eq!(sqrt, 3.0);
+        assert_eq!(ln, f64::NEG_INFINITY);
+        assert_eq!(log2, -1.0);
+        assert_eq!(log10, f64::INFINITY);
+
+        let asin: R64 = su64(1.0).asin();
+        let acos: NN64 = su64(-1.0).acos();
+        assert_eq!(asin, consts::FRAC_PI_2);
+        assert_eq!(acos, consts::PI);
+    }
+
+    #[test]
+    fn capability_markers() {
+        use crate::{
+            checkers::{self, Capabilities},
+            FloatChecker,
+        };
+        use num_traits::{Bounded, One, Zero};
+
+        assert_eq!(NNX64::infinity(), f64::INFINITY);
+        assert_eq!(NGX64::neg_infinity(), f64::NEG_INFINITY);
+        assert!(NP64::neg_zero().raw().is_sign_negative());
+        assert_eq!(P64::one(), 1.0);
+        assert_eq!(NN32::zero(), 0.0);
+        assert_eq!(NR64::default(), 0.0);
+        assert_eq!(<NZ64 as Bounded>::max_value(), f64::MAX);
+        assert_eq!(U64::default(), 0.0);
+        assert_eq!(SU64::one(), 1.0);
+        assert_eq!(<P64 as Float>::max_value(), f64::MAX);
+        assert_eq!(<NG64 as Bounded>::min_value(), f64::MIN);
+        assert_eq!(<NR64 as Float>::epsilon(), f64::EPSILON);
+
+        type Fraction = crate::ranged!(f64, -1.0 < x < 1.0);
+        type NonNegativeNum =
+            checkers::And<checkers::NumChecker, checkers::NonNegativeFiniteChecker>;
+
+        let fraction: Fraction = Zero::zero();
+        assert_eq!(fraction, 0.0);
+        assert_eq!(
+            <checkers::RangeChecker<0xBFF0000000000000, 0x3FF0000000000000, false, false> as FloatChecker<f64>>::CAPABILITIES,
+            Capabilities {
+                zero: true,
+                one: false,
+                positive: false,
+                negative: false,
+                infinity: false,
+            }
+        );
+        assert_eq!(
+            <NonNegativeNum as FloatChecker<f64>>::CAPABILITIES,
+            <checkers::NonNegativeFiniteChecker as FloatChecker<f64>>::CAPABILITIES
+        );
+    }
+
+    #[test]
+    fn generic_float_constructors() {
+        // only mentions the constructors, which must compile for every alias implementing `Float`,
+        // which are all but `U32` and `U64`
+        fn constant<T: Float>(x: T, which: Option<u8>) -> T {
+            match which {
+                Some(0) => T::nan(),
+                Some(1) => T::infinity(),
+                Some(2) => T::neg_infinity(),
+                Some(3) => T::neg_zero(),
+                Some(4) => T::min_value(),
+                Some(5) => T::max_value(),
+                Some(6) => T::min_positive_value(),
+                Some(_) => T::epsilon(),
+                None => x,
+            }
+        }
+
+        macro_rules! all_aliases {
+            ($($value:literal => $($alias:ident),+;)+) => {
+                $($(assert_eq!(constant($alias::new($value), None), $value);)+)+
+            };
+        }
+
+        all_aliases!(
+            0.5 => N32, N64, R32, R64, P32, P64, PX32, PX64, NN32, NN64, NNX32, NNX64;
+            0.5 => NZ32, NZ64, NZX32, NZX64, NR32, NR64, SU32, SU64;
+            0.5 => StrictN32, StrictN64, StrictR32, StrictR64, FlaggedR32, FlaggedR64;
+            -0.5 => NG32, NG64, NGX32, NGX64, NP32, NP64, NPX32, NPX64;
+        );
+        assert_eq!(constant(r64(1.0), Some(5)), f64::MAX);
+        assert_eq!(constant(nn32(1.0), Some(7)), f32::EPSILON);
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected NaN")]
+    fn generic_float_nan() {
+        fn nan<T: Float>() -> T {
+            T::nan()
+        }
+
+        let _: R64 = nan();
+    }
+
+    #[test]
+    fn sign_refined_math() {
+        // `Float` is implemented for all types, whether or not they admit zero and one.
+        let p: P64 = p64(2.0).powi(2);
+        let e: P64 = Float::exp(p64(1.0));
+        let r: PX64 = p64(4.0).recip();
+        assert_eq!(p, 4.0);
+        assert_eq!(e, std::f64::consts::E);
+        assert_eq!(r, 0.25);
+        assert_eq!(Float::sqrt(p64(4.0)), p64(2.0));
+
+        let abs: P64 = ng64(-2.0).abs();
+        let abs_zero: NNX64 = npx64(-0.0).abs();
+        let abs_non_zero: PX64 = nzx64(f64::NEG_INFINITY).abs();
+        assert_eq!(abs, 2.0);
+        assert_eq!(abs_zero, 0.0);
+        assert_eq!(abs_non_zero, f64::INFINITY);
+
+        let exp_positive: PX64 = nn64(0.0).exp();
+        let overflow: PX64 = p64(1000.0).exp();
+        assert_eq!(exp_positive, 1.0);
+        assert_eq!(overflow, f64::INFINITY);
+
+        let exp: U64 = ng64(-1.0).exp();
+        let underflow: U64 = ngx64(f64::NEG_INFINITY).exp();
+        assert_eq!(exp, (-1.0f64).exp());
+        assert_eq!(underflow, 0.0);
+
+        let recip: NZX64 = nz64(f64::MIN_POSITIVE / 4.0).recip();
+        assert_eq!(recip, f64::INFINITY);
+        assert_eq!(ng64(-4.0).recip(), -0.25);
+    }
+
+    #[test]
+    fn lattice_conversions() {
+        use crate::checkers::NonNegativeFiniteChecker;
+        use std::convert::TryFrom;
+
+        let nn: NN64 = p64(2.0).into();
+        let r: R64 = nn.widen();
+        let n = N64::from(nz64(-3.0));
+        assert_eq!(nn, 2.0);
+        assert_eq!(r, 2.0);
+        assert_eq!(n, -3.0);
+        assert_eq!(N64::from(su64(0.5)), 0.5);
+
+        assert_eq!(r.narrow::<NonNegativeFiniteChecker>(), Some(nn));
+        assert_eq!((-r).narrow::<NonNegativeFiniteChecker>(), None);
+        assert_eq!(n.try_into_checker::<NonNegativeFiniteChecker>(), None);
+        assert_eq!(
+            P64::try_from(r64(0.0)).map_err(|e| e.reason()),
+            Err(crate::InvalidReason::Zero)
+        );
+        assert_eq!(SU64::try_from(n64(-1.0)).unwrap(), -1.0);
+    }
+
+    #[test]
+    fn join_table() {
+        use crate::{checkers::*, FloatChecker};
+        use core::any;
+
+        const SAMPLES: [f64; 14] = [
+            f64::NEG_INFINITY,
+            f64::MIN,
+            -2.0,
+            -1.0,
+            -0.5,
+            -f64::MIN_POSITIVE / 2.0,
+            -0.0,
+            0.0,
+            f64::MIN_POSITIVE / 2.0,
+            0.5,
+            1.0,
+            2.0,
+            f64::MAX,
+            f64::INFINITY,
+        ];
+
+        // The output of joining `A` and `B` admits all values of both,
+        // and is contained in every standard checker that admits them.
+        fn joins<A, B>(standard: &[fn(f64) -> bool])
+        where
+            A: FloatChecker<f64> + Join<B>,
+            B: FloatChecker<f64>,
+            A::Output: FloatChecker<f64>,
+        {
+            let name = any::type_name::<(A, B)>();
+            let either = |value| A::check(value) || B::check(value);
+            for &value in &SAMPLES {
+                assert!(
+                    !either(value) || A::Output::check(value),
+                    "{} {}",
+                    name,
+                    value
+                );
+            }
+            for check in standard {
+                if SAMPLES.iter().all(|&value| !either(value) || check(value)) {
+                    for &value in &SAMPLES {
+                        assert!(
+                            !A::Output::check(value) || check(value),
+                            "{} {}",
+                            name,
+                            value
+                        );
+                    }
+                }
+            }
+        }
+
+        macro_rules! all_pairs {
+            ($($checker:ty),+) => {
+                let standard: &[fn(f64) -> bool] = &[$(<$checker as FloatChecker<f64>>::check),+];
+                all_pairs!(@rows standard [$($checker),+] $($checker),+);
+            };
+            (@rows $standard:ident $all:tt $($checker:ty),+) => {
+                $(all_pairs!(@row $standard $checker $all);)+
+            };
+            (@row $standard:ident $checker:ty [$($other:ty),+]) => {
+                $(joins::<$checker, $other>($standard);)+
+            };
+        }
+
+        all_pairs!(
+            NumChecker,
+            FiniteChecker,
+            PositiveChecker,
+            PositiveFiniteChecker,
+            NonNegativeChecker,
+            NonNegativeFiniteChecker,
+            NegativeChecker,
+            NegativeFiniteChecker,
+            NonPositiveChecker,
+            NonPositiveFiniteChecker,
+            NonZeroChecker,
+            NonZeroFiniteChecker,
+            NormalChecker,
+            SignedUnitChecker
+        );
+
+        // `UnitChecker` joins with every other checker, but not with itself.
+        macro_rules! unit_pairs {
+            ($($other:ty),+) => {
+                let standard: &[fn(f64) -> bool] = &[
+                    <UnitChecker as FloatChecker<f64>>::check,
+                    $(<$other as FloatChecker<f64>>::check),+
+                ];
+                $(
+                    joins::<UnitChecker, $other>(standard);
+                    joins::<$other, UnitChecker>(standard);
+                )+
+            };
+        }
+
+        unit_pairs!(
+            NumChecker,
+            FiniteChecker,
+            PositiveChecker,
+            PositiveFiniteChecker,
+            NonNegativeChecker,
+            NonNegativeFiniteChecker,
+            NegativeChecker,
+            NegativeFiniteChecker,
+            NonPositiveChecker,
+            NonPositiveFiniteChecker,
+            NonZeroChecker,
+            NonZeroFiniteChecker,
+            NormalChecker,
+            SignedUnitChecker
+        );
+    }
+
+    #[test]
+    fn mixed_operands() {
+        let sum: N64 = r64(1.0) + n64(2.0);
+        let product: NN64 = p64(2.0) * nn64(3.0);
+        let quotient: NZX64 = p64(1.0) / ngx64(-4.0);
+        let difference: R64 = su64(0.5) - nr64(2.0);
+        assert_eq!(sum, 3.0);
+        assert_eq!(product, 6.0);
+        assert_eq!(quotient, -0.25);
+        assert_eq!(difference, -1.5);
+        assert_eq!(r64(7.0) % nz64(4.0), 3.0);
+
+        let mut value = r64(1.0);
+        value += p64(2.0);
+        value *= nn64(2.0);
+        assert_eq!(value, 6.0);
+
+        assert_eq!(r64(2.0), n64(2.0));
+        assert!(p64(1.0) < nn64(2.0));
+        assert!(N64::infinity() > r64(1e300));
+
+        assert_eq!(r64(2.5) * 3i32, 7.5);
+        assert_eq!(2u8 - r32(0.5), 1.5);
+        assert_eq!(p64(9.0) / 3u32, p64(3.0));
+        assert_eq!(r64(3.0), 3i16);
+        assert!(-1i8 < nn32(0.0));
+    }
+
+    struct Integer;
+
+    impl crate::checkers::Predicate for Integer {
+        const MESSAGE: &'static str = "unexpected NaN or fractional value";
+
+        fn test(value: f64) -> bool {
+            value.fract() == 0.0
+        }
+    }
+
+    #[test]
+    fn checker_combinators() {
+        use crate::{
+            checkers::{And, FiniteChecker, NormalChecker, PositiveChecker, Satisfies, Strict},
+            FloatChecker, NoisyFloat,
+        };
+        use num_traits::One;
+
+        type PositiveNormal = NoisyFloat<f64, And<PositiveChecker, NormalChecker>>;
+        type Whole = NoisyFloat<f32, Strict<And<FiniteChecker, Satisfies<Integer>>>>;
+
+        assert_eq!(PositiveNormal::try_new(2.0).map(|x| x.raw()), Some(2.0));
+        assert_eq!(PositiveNormal::try_new(f64::INFINITY), None);
+        assert_eq!(PositiveNormal::try_new(f64::MIN_POSITIVE / 2.0), None);
+        assert_eq!(PositiveNormal::one(), 1.0);
+        assert_eq!(Whole::new(2.0) + 3.0, 5.0);
+        assert_eq!(Whole::try_new(0.5), None);
+        assert_eq!(Whole::try_new(f32::NAN), None);
+        assert_eq!(
+            <And<PositiveChecker, NormalChecker>>::message(-1.0f64),
+            "unexpected NaN or non-positive value"
+        );
+        assert_eq!(
+            <And<PositiveChecker, NormalChecker>>::message(f64::INFINITY),
+            "unexpected NaN, infinity or subnormal value"
+        );
+
+        let strict: NoisyFloat<f64, Strict<FiniteChecker>> = r64(1.5).widen();
+        let finite: R64 = strict.widen();
+        assert_eq!(finite, 1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "3 / 2 produced 1.5")]
+    fn strict_predicate() {
+        use crate::{
+            checkers::{And, FiniteChecker, Satisfies, Strict},
+            NoisyFloat,
+        };
+
+        type Whole = NoisyFloat<f64, Strict<And<FiniteChecker, Satisfies<Integer>>>>;
+        let _ = Whole::new(3.0) / 2.0;
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[should_panic(expected = "unexpected NaN or fractional value")]
+    fn satisfies_predicate() {
+        use crate::{checkers::Satisfies, NoisyFloat};
+
+        let _ = NoisyFloat::<f64, Satisfies<Integer>>::new(0.5);
+    }
+
     #[test]
     fn resolves_min_max() {
         assert_eq!(r64(1.0).min(r64(3.0)), r64(1.0));
@@ -435,6 +1262,216 @@ mod tests {
         assert_eq!(R64::epsilon(), f64::EPSILON);
     }
 
+    #[test]
+    fn invalid_float_errors() {
+        use crate::{
+            checkers::{And, FiniteChecker, Satisfies},
+            InvalidFloat, InvalidReason, NoisyFloat,
+        };
+        use std::{convert::TryFrom, string::ToString};
+
+        type Whole = NoisyFloat<f64, And<FiniteChecker, Satisfies<Integer>>>;
+
+        assert_eq!(R64::try_new_checked(2.0), Ok(r64(2.0)));
+        assert_eq!(
+            N64::try_new_checked(f64::NAN).unwrap_err().reason(),
+            InvalidReason::Nan
+        );
+        assert_eq!(
+            R32::try_new_checked(f32::INFINITY),
+            Err(InvalidFloat::new(f32::INFINITY, InvalidReason::PosInfinity))
+        );
+        assert_eq!(
+            R64::try_from(f64::NEG_INFINITY).unwrap_err().reason(),
+            InvalidReason::NegInfinity
+        );
+        assert_eq!(
+            NZ64::try_new_checked(-0.0).unwrap_err().reason(),
+            InvalidReason::Zero
+        );
+        assert_eq!(
+            NN64::try_new_checked(-1.0).unwrap_err().reason(),
+            InvalidReason::OutOfRange
+        );
+        assert_eq!(
+            NR64::try_new_checked(f64::MIN_POSITIVE / 2.0)
+                .unwrap_err()
+                .reason(),
+            InvalidReason::Subnormal
+        );
+        assert_eq!(
+            SU64::try_new_checked(-2.0).unwrap_err().reason(),
+            InvalidReason::OutOfRange
+        );
+        assert_eq!(
+            Whole::try_new_checked(0.5).unwrap_err().reason(),
+            InvalidReason::Custom("unexpected NaN or fractional value")
+        );
+        assert_eq!(
+            Whole::try_new_checked(f64::INFINITY).unwrap_err().reason(),
+            InvalidReason::PosInfinity
+        );
+
+        let error = P64::try_new_checked(-1.5).unwrap_err();
+        assert_eq!(error.value(), -1.5);
+        assert_eq!(error.to_string(), "invalid value -1.5 (value out of range)");
+    }
+
+    #[test]
+    fn checked_operations() {
+        use crate::InvalidReason;
+        use num_traits::{CheckedAdd, CheckedDiv, CheckedNeg};
+
+        assert_eq!(r64(1.0).checked_add(r64(2.0)), Ok(r64(3.0)));
+        assert_eq!(
+            r64(1.0).checked_div(r64(0.0)).unwrap_err().reason(),
+            InvalidReason::PosInfinity
+        );
+        assert_eq!(
+            n64(0.0).checked_div(n64(0.0)).unwrap_err().reason(),
+            InvalidReason::Nan
+        );
+        assert_eq!(
+            r64(f64::MAX).checked_mul(r64(2.0)).unwrap_err().value(),
+            f64::INFINITY
+        );
+        assert_eq!(
+            nn64(1.0).checked_sub(nn64(2.0)).unwrap_err().reason(),
+            InvalidReason::OutOfRange
+        );
+        assert_eq!(p64(2.0).checked_neg().unwrap_err().value(), -2.0);
+        assert_eq!(
+            r64(-4.0).checked_sqrt().unwrap_err().reason(),
+            InvalidReason::Nan
+        );
+        assert_eq!(
+            r64(0.0).checked_ln().unwrap_err().reason(),
+            InvalidReason::NegInfinity
+        );
+        assert_eq!(r64(8.0).checked_log(r64(2.0)), Ok(r64(3.0)));
+        assert_eq!(r32(2.0).checked_powi(3), Ok(r32(8.0)));
+        assert!(r64(1e300).checked_powf(r64(2.0)).is_err());
+        assert_eq!(r64(2.0).checked_mul_add(r64(3.0), r64(1.0)), Ok(r64(7.0)));
+        assert_eq!(r64(0.0).checked_sin_cos(), Ok((r64(0.0), r64(1.0))));
+        assert!(r64(2.0).checked_atanh().is_err());
+
+        assert_eq!(
+            CheckedAdd::checked_add(&r64(1.0), &r64(2.0)),
+            Some(r64(3.0))
+        );
+        assert_eq!(CheckedDiv::checked_div(&r64(1.0), &r64(0.0)), None);
+        assert_eq!(CheckedNeg::checked_neg(&n64(1.0)), Some(n64(-1.0)));
+    }
+
+    #[test]
+    fn noisy_result() {
+        use crate::{
+            checkers::{FiniteChecker, PositiveFiniteChecker},
+            InvalidReason, NoisyResult,
+        };
+
+        let x = NoisyResult::from(r64(3.0));
+        let y: NoisyResult<f64, _> = (x * r64(2.0) + 1.0).sqrt() - x;
+        assert_eq!(y.into_result(), Ok(r64(7.0f64.sqrt() - 3.0)));
+
+        let mut z = NoisyResult::<f64, PositiveFiniteChecker>::new(2.0);
+        z -= 2.0;
+        z *= p64(4.0);
+        z += NoisyResult::new(1.0);
+        let error = z.into_result().unwrap_err();
+        assert_eq!(error.value(), 0.0);
+        assert_eq!(error.reason(), InvalidReason::Zero);
+        let operation = error.operation().unwrap();
+        assert_eq!(operation.name(), "-");
+        assert_eq!(operation.operands(), &[2.0, 2.0]);
+
+        let infinite = NoisyResult::from(r64(1.0)) / r64(0.0);
+        let first = (NoisyResult::from(r64(-1.0)).ln() + infinite).into_result();
+        assert_eq!(first.unwrap_err().operation().unwrap().name(), "ln");
+        assert!(NoisyResult::<f32, FiniteChecker>::new(f32::NAN).is_err());
+        assert_eq!(NoisyResult::from(r64(0.0)).sin_cos().1.ok(), Some(r64(1.0)));
+        assert_eq!(
+            NoisyResult::from(r64(f64::MAX))
+                .max(NoisyResult::from(r64(1.0)) * 0.5)
+                .ok(),
+            Some(r64(f64::MAX))
+        );
+        assert!((-NoisyResult::from(r64(2.0)) / r64(0.0)).is_err());
+    }
+
+    #[test]
+    fn noisy_result_operands() {
+        use crate::{checkers::PositiveFiniteChecker, NoisyResult};
+
+        let r = NoisyResult::from(r64(3.0));
+        assert_eq!((2.0 * r).ok(), Some(r64(6.0)));
+        assert_eq!((r + 1i32).ok(), Some(r64(4.0)));
+        assert_eq!((1u32 - &r).ok(), Some(r64(-2.0)));
+        assert_eq!((1.5f32 / NoisyResult::from(r32(3.0))).ok(), Some(r32(0.5)));
+        assert_eq!((NoisyResult::from(r32(3.5)) % 2u8).ok(), Some(r32(1.5)));
+
+        let p = NoisyResult::<f64, PositiveFiniteChecker>::new(2.0);
+        let sum: NoisyResult<f64, _> = p + r;
+        let product = p64(4.0) * r;
+        let difference = p - nn64(2.0);
+        assert_eq!(sum.into_result(), Ok(r64(5.0)));
+        assert_eq!(product.into_result(), Ok(r64(12.0)));
+        assert!(difference.is_ok());
+        assert_eq!(difference.into_result(), Ok(nn64(0.0)));
+
+        let error = (0.0 - p).into_result().unwrap_err();
+        assert_eq!(error.operation().unwrap().operands(), &[0.0, 2.0]);
+        let error = (1i8 / NoisyResult::from(r64(0.0)) - r)
+            .into_result()
+            .unwrap_err();
+        assert_eq!(error.operation().unwrap().name(), "/");
+    }
+
+    #[test]
+    fn saturating_arithmetic() {
+        use crate::{ranged, Saturating};
+
+        type Gain = ranged!(f64, 0.0 <= x <= 1.0);
+
+        let huge = Saturating(r64(f64::MAX));
+        assert_eq!((huge * 2.0).raw(), f64::MAX);
+        assert_eq!((-huge - huge).raw(), f64::MIN);
+        assert_eq!(huge.exp().raw(), f64::MAX);
+
+        let mut remaining = Saturating(NN64::new(1.0));
+        remaining -= 3.0;
+        assert_eq!(remaining.raw(), 0.0);
+        assert_eq!(
+            (Saturating(p64(1e-300)) * 1e-300).raw(),
+            f64::MIN_POSITIVE * f64::EPSILON
+        );
+        assert_eq!(
+            Saturating(NZ64::new(1.0)) - 1.0,
+            Saturating::new(f64::MIN_POSITIVE * f64::EPSILON)
+        );
+
+        assert_eq!(U64::new_clamped(1.5).raw(), 1.0);
+        assert_eq!(SU32::new_clamped(-7.0).raw(), -1.0);
+        assert_eq!((Saturating(Gain::new(0.75)) + 0.5).raw(), 1.0);
+        assert_eq!(Gain::new_clamped(-0.25).raw(), 0.0);
+        assert_eq!(Saturating(r64(0.0)).sin_cos().1.raw(), 1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot clamp NaN")]
+    fn saturating_nan() {
+        let _ = crate::Saturating(r64(0.0)) / 0.0;
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn invalid_float_is_error() {
+        use std::{boxed::Box, error::Error, string::ToString};
+
+        let error: Box<dyn Error> = Box::new(R64::try_new_checked(f64::NAN).unwrap_err());
+        assert_eq!(error.to_string(), "invalid value NaN (NaN)");
+    }
+
     #[test]
     fn test_try_into() {
         use std::convert::{TryFrom, TryInto};
@@ -523,6 +1560,19 @@ mod tests {
         assert!(got.is_err());
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_invalid_p64() {
+        use std::string::ToString;
+
+        let src = "-1.0";
+        let got: Result<P64, _> = serde_json::from_str(src);
+        assert!(got
+            .unwrap_err()
+            .to_string()
+            .starts_with("invalid NoisyFloat: value out of range"));
+    }
+
     // Make sure you can use serde_derive with noisy floats.
     #[cfg(feature = "serde")]
     #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -579,5 +1629,590 @@ mod tests {
         assert_eq!(B_RAW, 2.0);
         assert_eq!(C_RAW, 3.0);
         assert_eq!(D_RAW, 4.0);
+
+        const E: P64 = P64::unchecked_new(5.0);
+        const F: NPX32 = NPX32::unchecked_new(f32::NEG_INFINITY);
+        const G: NZ64 = NZ64::unchecked_new(-6.0);
+        assert_eq!(E.const_raw(), 5.0);
+        assert_eq!(F.const_raw(), f32::NEG_INFINITY);
+        assert_eq!(G.const_raw(), -6.0);
+
+        const H: StrictR64 = StrictR64::unchecked_new(7.0);
+        assert_eq!(H.const_raw(), 7.0);
+    }
+
+    #[test]
+    fn strict_types() {
+        assert_eq!(strict_r64(2.0) * 3.0, 6.0);
+        assert_eq!(strict_n32(f32::INFINITY), StrictN32::infinity());
+        assert_eq!(StrictR32::try_new(f32::INFINITY), None);
+        let widened: R64 = strict_r64(1.5).widen();
+        assert_eq!(widened, r64(1.5));
+    }
+
+    #[test]
+    #[should_panic(expected = "R64: 1 / 0 produced inf")]
+    fn strict_r64_infinity() {
+        let _ = strict_r64(1.0) / 0.0;
+    }
+
+    #[test]
+    #[should_panic(expected = "N64: 0 * inf produced NaN")]
+    fn strict_n64_nan() {
+        let _ = strict_n64(0.0) * f64::INFINITY;
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[should_panic(expected = "R64: ln(-3.2) produced NaN")]
+    fn operation_panic_message() {
+        let _ = r64(-3.2).ln();
+    }
+
+    #[test]
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[should_panic(expected = "P32: 0.1 - 0.1 produced 0")]
+    fn operation_panic_message_f32() {
+        let _ = p32(0.1) - p32(0.1);
+    }
+
+    #[test]
+    fn sticky_flags() {
+        use crate::flags::{self, Flags};
+
+        flags::clear();
+        let mut x = FlaggedR64::new(1.0);
+        x += 1.0;
+        assert!(flags::get().is_empty());
+
+        let tiny = FlaggedR32::new(f32::MIN_POSITIVE) / 2.0;
+        assert_eq!(flags::take(), Flags::SUBNORMAL);
+        assert!(tiny.raw() > 0.0);
+
+        let _ = x / 0.0;
+        assert!(flags::test(Flags::DIVIDE_BY_ZERO));
+        let _ = -x * f64::MAX;
+        let nan = (x - x) / 0.0;
+        assert!(nan.raw().is_nan());
+        let raised = flags::take();
+        assert_eq!(
+            raised,
+            Flags::DIVIDE_BY_ZERO | Flags::OVERFLOW | Flags::INVALID
+        );
+        assert!(!raised.contains(Flags::ALL));
+        assert!(!flags::test(Flags::ALL));
+
+        x /= 0.0;
+        flags::clear();
+        assert_eq!(flags::get(), Flags::NONE);
+        assert!(x.raw().is_infinite());
+    }
+
+    #[test]
+    fn sticky_flags_num() {
+        use crate::{
+            checkers::{Flagged, NumChecker},
+            flags::{self, Flags},
+            NoisyFloat,
+        };
+
+        type FlaggedN64 = NoisyFloat<f64, Flagged<NumChecker>>;
+
+        flags::clear();
+        let one = FlaggedN64::new(1.0);
+        let zero = FlaggedN64::new(0.0);
+        let inf = one / 0.0;
+        assert!(inf.raw().is_infinite());
+        assert_eq!(flags::take(), Flags::DIVIDE_BY_ZERO);
+
+        let _ = zero.ln();
+        assert_eq!(flags::take(), Flags::DIVIDE_BY_ZERO);
+        let _ = -one / zero;
+        let _ = zero.recip();
+        assert_eq!(flags::take(), Flags::DIVIDE_BY_ZERO);
+
+        let _ = one * f64::MAX * 2.0;
+        let _ = (one * 1000.0).exp();
+        assert_eq!(flags::take(), Flags::OVERFLOW);
+
+        // infinite operands give infinite results without raising a flag
+        let _ = inf + 1.0;
+        let _ = inf / zero;
+        let _ = FlaggedN64::new(f64::INFINITY).ln();
+        assert_eq!(flags::take(), Flags::NONE);
+
+        let nan = FlaggedN64::new(f64::NAN);
+        assert!(nan.raw().is_nan());
+        let _ = inf - inf;
+        assert_eq!(flags::take(), Flags::INVALID);
+        let _ = zero / zero;
+        assert_eq!(flags::take(), Flags::INVALID);
+    }
+
+    // `violation::set_handler` and `sampling::set_callback` change how the violations
+    // of every test are reported
+    static HANDLERS: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn sampled_checking() {
+        use crate::{
+            checkers::{NonNegativeFiniteChecker, Sampled},
+            sampling,
+            violation::Violation,
+            NoisyFloat,
+        };
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        type Probed = NoisyFloat<f64, Sampled<NonNegativeFiniteChecker>>;
+
+        static REPORTED: AtomicUsize = AtomicUsize::new(0);
+        fn report(violation: &Violation) {
+            assert_eq!(violation.checker(), "NN64");
+            assert_eq!(violation.operation().unwrap().name(), "-");
+            REPORTED.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let _guard = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+        sampling::set_callback(report);
+        sampling::set_rate(0);
+        let negative = Probed::new(1.0) - 2.0;
+        assert_eq!(negative, -1.0);
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 0);
+
+        sampling::set_rate(1);
+        for i in 0..4 {
+            let _ = Probed::new(1.0) - 2.0;
+            let _ = Probed::new(f64::from(i)) - 5.0;
+        }
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 2);
+        sampling::reset_sites();
+        let _ = Probed::new(1.0) - 2.0;
+        assert_eq!(REPORTED.load(Ordering::Relaxed), 3);
+
+        sampling::set_rate(sampling::DEFAULT_RATE);
+        sampling::reset_callback();
+        assert!(sampling::callback().is_none());
+    }
+
+    // `stats::reset` clears the sites of every test using profiling
+    #[cfg(feature = "profiling")]
+    static PROFILING: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn profiling_counters() {
+        use crate::stats::{self, SiteStats};
+
+        let _guard = PROFILING.lock().unwrap_or_else(|e| e.into_inner());
+
+        // other tests run concurrently, so only look at the sites of this test
+        fn site(line: u32) -> SiteStats {
+            stats::snapshot()
+                .into_iter()
+                .find(|site| site.file() == file!() && site.line() == line)
+                .unwrap()
+        }
+
+        let construct_line = line!() + 2;
+        for i in 0..10 {
+            let x = strict_r64(f64::from(i));
+            let _ = x.sqrt();
+        }
+        let construct = site(construct_line);
+        assert_eq!(construct.checker(), "R64");
+        assert_eq!(
+            (
+                construct.constructions(),
+                construct.operations(),
+                construct.checks(),
+                construct.violations()
+            ),
+            (10, 0, 10, 0)
+        );
+        let sqrt = site(construct_line + 1);
+        assert_eq!((sqrt.constructions(), sqrt.operations()), (10, 10));
+        assert_eq!(sqrt.checks(), 10);
+
+        let mut sum = strict_r64(0.0);
+        let add_assign_line = line!() + 2;
+        for i in 0..5 {
+            sum += f64::from(i);
+        }
+        let add_assign = site(add_assign_line);
+        assert_eq!((add_assign.operations(), add_assign.checks()), (5, 5));
+        assert_eq!(sum, 10.0);
+
+        let report = stats::snapshot();
+        assert!(report.windows(2).all(|pair| {
+            (
+                pair[0].violations(),
+                pair[0].checks(),
+                pair[0].constructions(),
+            ) >= (
+                pair[1].violations(),
+                pair[1].checks(),
+                pair[1].constructions(),
+            )
+        }));
+        assert!(std::format!("{}", construct)
+            .ends_with("R64: 10 constructions, 0 operations, 10 checks, 0 violations"));
+
+        stats::reset();
+        assert!(stats::snapshot()
+            .iter()
+            .all(|site| site.file() != file!() || site.line() != construct_line));
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn profiling_counters_threads() {
+        use crate::stats;
+
+        let _guard = PROFILING.lock().unwrap_or_else(|e| e.into_inner());
+
+        let line = line!() + 3;
+        fn construct() {
+            for i in 0..1000 {
+                let _ = strict_r64(f64::from(i));
+            }
+        }
+
+        let threads: std::vec::Vec<_> = (0..4).map(|_| std::thread::spawn(construct)).collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+        let site = stats::snapshot()
+            .into_iter()
+            .find(|site| site.file() == file!() && site.line() == line)
+            .unwrap();
+        assert_eq!((site.constructions(), site.checks()), (4000, 4000));
+    }
+
+    #[cfg(feature = "profiling")]
+    #[test]
+    fn range_profiling() {
+        use crate::{
+            checkers::{FiniteChecker, RangeProfiler},
+            stats, NoisyFloat,
+        };
+
+        type Profiled = NoisyFloat<f32, RangeProfiler<FiniteChecker>>;
+
+        let _guard = PROFILING.lock().unwrap_or_else(|e| e.into_inner());
+
+        fn site(line: u32) -> stats::SiteRange {
+            stats::ranges()
+                .into_iter()
+                .find(|site| site.file() == file!() && site.line() == line)
+                .unwrap()
+        }
+
+        let line = line!() + 2;
+        for &x in [0.0, -0.0, 1e-40, -3.0, 0.25].iter() {
+            let _ = Profiled::new(x);
+        }
+        let range = site(line);
+        assert_eq!(range.checker(), "R32");
+        assert_eq!(range.values(), 5);
+        assert_eq!((range.zeros(), range.subnormals()), (2, 1));
+        assert_eq!(range.min_abs(), Some(f64::from(1e-40f32)));
+        assert_eq!(range.max_abs(), Some(3.0));
+        assert!(!range.fits_f32());
+
+        let line = line!() + 1;
+        let product = Profiled::new(0.0) * 4.0;
+        assert_eq!(product, 0.0);
+        let zeros = site(line);
+        assert_eq!((zeros.values(), zeros.zeros()), (2, 2));
+        assert_eq!((zeros.min_abs(), zeros.max_abs()), (None, None));
+        assert!(zeros.fits_f32());
+        assert!(std::format!("{}", zeros).ends_with("R32: 2 values, 0 subnormals, 2 zeros"));
+    }
+
+    #[test]
+    fn parsing() {
+        use crate::{InvalidReason, ParseError};
+        use num_traits::Num;
+        use std::string::ToString;
+
+        assert_eq!("1.5".parse::<R64>(), Ok(r64(1.5)));
+        assert_eq!("-0.25".parse::<N32>(), Ok(n32(-0.25)));
+        assert_eq!("inf".parse::<N64>(), Ok(N64::infinity()));
+        assert!(matches!("".parse::<R64>(), Err(ParseError::Syntax(_))));
+        assert!(matches!("one".parse::<P32>(), Err(ParseError::Syntax(_))));
+
+        let error = "-inf".parse::<R32>().unwrap_err();
+        match &error {
+            ParseError::Invalid(invalid) => {
+                assert_eq!(invalid.reason(), InvalidReason::NegInfinity);
+                assert!(invalid.operation().is_none());
+            }
+            ParseError::Syntax(_) => panic!("unexpected syntax error"),
+        }
+        assert_eq!(error.to_string(), "invalid value -inf (negative infinity)");
+        assert_eq!(
+            "x".parse::<R64>().unwrap_err().to_string(),
+            "invalid float literal"
+        );
+        assert!(matches!("NaN".parse::<N64>(), Err(ParseError::Invalid(_))));
+        assert!(matches!("-2".parse::<NN64>(), Err(ParseError::Invalid(_))));
+
+        assert_eq!(R64::from_str_radix("ff", 16).unwrap(), 255.0);
+        assert_eq!(R64::from_str_radix("1.5", 10).unwrap(), 1.5);
+        assert!(matches!(
+            R64::from_str_radix("12", 2),
+            Err(ParseError::Syntax(_))
+        ));
+        assert!(matches!(
+            R64::from_str_radix("inf", 10),
+            Err(ParseError::Invalid(_))
+        ));
+    }
+
+    // `shadow::set_tolerance` changes the tolerance of every test using `Shadowed`
+    static SHADOW: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    #[test]
+    fn shadowed_arithmetic() {
+        use crate::shadow::{self, Shadowed};
+
+        let _guard = SHADOW.lock().unwrap_or_else(|e| e.into_inner());
+        let x = Shadowed::<R32>::new(2.0);
+        let root = x.sqrt();
+        assert_eq!(root.raw(), 2f32.sqrt());
+        assert_eq!(root.shadow(), 2f64.sqrt());
+        assert!(root.relative_error() < 1e-7);
+        assert_eq!(root.powi(2).shadow(), 2f64.sqrt().powi(2));
+        assert_eq!(x.sin_cos().0.raw(), 2f32.sin());
+
+        let mut sum = Shadowed::<R32>::default();
+        for _ in 0..10 {
+            sum += x / 10.0;
+        }
+        assert!(sum.relative_error() > 0.0);
+        assert!((sum.shadow() - 2.0).abs() < 1e-12);
+        assert_eq!(-&x, Shadowed::new(-2.0));
+        assert!(x < root * 2.0);
+        assert_eq!(std::format!("{}", x), "2");
+
+        // errors are only reported where they first exceed the tolerance
+        shadow::set_tolerance(f64::INFINITY);
+        let lost = (x + 1e8) - 1e8;
+        assert_eq!((lost.raw(), lost.shadow()), (0.0, 2.0));
+        shadow::set_tolerance(shadow::DEFAULT_TOLERANCE);
+        assert_eq!(shadow::tolerance(), 1e-4);
+        let doubled = lost * 2.0 + 1.0;
+        assert_eq!((doubled.raw(), doubled.shadow()), (1.0, 5.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "R32: 100000000 - 100000000 produced 0 instead of 1.5")]
+    fn shadowed_cancellation() {
+        use crate::Shadowed;
+
+        let _guard = SHADOW.lock().unwrap_or_else(|e| e.into_inner());
+        let _handlers = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+        let big = Shadowed::<R32>::new(1e8);
+        let _ = (big + 1.5) - big;
+    }
+
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[test]
+    fn cancellation() {
+        use crate::{
+            checkers::{Cancellation, FiniteChecker},
+            NoisyFloat,
+        };
+
+        type Guarded = NoisyFloat<f64, Cancellation<FiniteChecker, 20>>;
+
+        let _guard = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+        let x = Guarded::new(1.0);
+        assert_eq!(x - 0.5, 0.5);
+        assert_eq!(x + 1e-7, 1.0000001);
+        assert_eq!(x + x, 2.0);
+        assert_eq!(1e-9 - x, -0.999999999);
+        assert_eq!(x - 0.25 - 1.0, -0.25);
+        assert_eq!(Guarded::new(0.0) - x, -1.0);
+        assert_eq!(-x + 0.0, -1.0);
+    }
+
+    #[cfg(any(debug_assertions, feature = "strict"))]
+    #[test]
+    #[should_panic(
+        expected = "R32: 1.0000001 + -1 produced 0.00000011920929, a catastrophic cancellation"
+    )]
+    fn cancellation_panic() {
+        use crate::{
+            checkers::{Cancellation, FiniteChecker},
+            NoisyFloat,
+        };
+
+        type Guarded = NoisyFloat<f32, Cancellation<FiniteChecker, 16>>;
+
+        let _guard = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+        let mut x = Guarded::new(1.0000001);
+        x += -1.0;
+    }
+
+    #[test]
+    fn violation_handler() {
+        use crate::{
+            checkers::{NonZeroFiniteChecker, Strict},
+            violation::{self, Action, Violation},
+            NoisyFloat,
+        };
+        use core::sync::atomic::{AtomicU32, Ordering};
+
+        // only continues for this violation, so that concurrent tests still panic
+        static LINE: AtomicU32 = AtomicU32::new(0);
+        fn record(violation: &Violation) -> Action {
+            if violation.checker() == "NZ64" && violation.value() == 0.0 {
+                assert_eq!(violation.message(), "unexpected NaN, infinity or zero");
+                let operation = violation.operation().unwrap();
+                assert_eq!(
+                    (operation.name(), operation.operands()),
+                    ("-", &[2.0, 2.0][..])
+                );
+                assert_eq!(violation.location().file(), file!());
+                LINE.store(violation.location().line(), Ordering::Relaxed);
+                Action::Continue
+            } else if violation.checker() == "NZ64" && violation.value() == f64::INFINITY {
+                Action::Replace(f64::MAX)
+            } else {
+                Action::Panic
+            }
+        }
+
+        let _guard = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+        violation::set_handler(record);
+        let x = NoisyFloat::<f64, Strict<NonZeroFiniteChecker>>::new(2.0);
+        let line = line!() + 1;
+        let zero = x - x;
+        assert_eq!(zero.raw(), 0.0);
+        assert_eq!(LINE.load(Ordering::Relaxed), line);
+        assert_eq!(x / zero, f64::MAX);
+        let mut infinity = f64::INFINITY;
+        assert_eq!(
+            *NoisyFloat::<f64, Strict<NonZeroFiniteChecker>>::borrowed_mut(&mut infinity),
+            f64::MAX
+        );
+        assert!(violation::handler().is_some());
+        violation::reset_handler();
+        assert!(violation::handler().is_none());
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn log_violations() {
+        use crate::violation::{self, Action, Violation};
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use std::string::ToString;
+
+        static LOGGED: AtomicUsize = AtomicUsize::new(0);
+        struct Capture;
+        impl log::Log for Capture {
+            fn enabled(&self, _metadata: &log::Metadata) -> bool {
+                true
+            }
+            fn log(&self, record: &log::Record) {
+                let field = |key| record.key_values().get(log::kv::Key::from_str(key));
+                assert_eq!(record.target(), "noisy_float");
+                assert_eq!(record.level(), log::Level::Error);
+                assert_eq!(record.args().to_string(), "unexpected NaN");
+                assert!(field("value")
+                    .and_then(|value| value.to_f64())
+                    .unwrap()
+                    .is_nan());
+                assert_eq!(field("checker").unwrap().to_borrowed_str(), Some("N64"));
+                assert_eq!(field("operation").unwrap().to_string(), "None");
+                assert_eq!(field("file").unwrap().to_borrowed_str(), Some(file!()));
+                assert_eq!(field("line").and_then(|line| line.to_u64()), Some(LINE));
+                LOGGED.fetch_add(1, Ordering::Relaxed);
+            }
+            fn flush(&self) {}
+        }
+
+        log::set_logger(&Capture).unwrap();
+        log::set_max_level(log::LevelFilter::Error);
+        const LINE: u64 = line!() as u64 + 1;
+        let violation = Violation::new(f64::NAN, "N64", "unexpected NaN");
+        assert_eq!(violation::log_and_continue(&violation), Action::Continue);
+        assert_eq!(violation::log_and_panic(&violation), Action::Panic);
+        assert_eq!(LOGGED.load(Ordering::Relaxed), 2);
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn trace_violations() {
+        use crate::{
+            checkers::FiniteChecker,
+            violation::{self, Action, Violation},
+            Operation,
+        };
+        use std::{fmt, format, string::String, sync::Mutex, vec::Vec};
+        use tracing::{
+            field::{Field, Visit},
+            span, Event, Metadata, Subscriber,
+        };
+
+        static EVENTS: Mutex<Vec<Vec<(&'static str, String)>>> = Mutex::new(Vec::new());
+
+        struct Fields(Vec<(&'static str, String)>);
+        impl Visit for Fields {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                self.0.push((field.name(), value.into()));
+            }
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                self.0.push((field.name(), format!("{:?}", value)));
+            }
+        }
+
+        struct Capture;
+        impl Subscriber for Capture {
+            fn enabled(&self, _metadata: &Metadata) -> bool {
+                true
+            }
+            fn new_span(&self, _span: &span::Attributes) -> span::Id {
+                span::Id::from_u64(1)
+            }
+            fn record(&self, _span: &span::Id, _values: &span::Record) {}
+            fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+            fn event(&self, event: &Event) {
+                assert_eq!(event.metadata().target(), "noisy_float");
+                assert_eq!(*event.metadata().level(), tracing::Level::ERROR);
+                let mut fields = Fields(Vec::new());
+                event.record(&mut fields);
+                EVENTS.lock().unwrap().push(fields.0);
+            }
+            fn enter(&self, _span: &span::Id) {}
+            fn exit(&self, _span: &span::Id) {}
+        }
+
+        let line = line!() + 1;
+        let violation = Violation::capture::<f64, FiniteChecker>(
+            f64::INFINITY,
+            Some(Operation::new("/", &[1.0, 0.0])),
+        );
+        tracing::subscriber::with_default(Capture, || {
+            assert_eq!(violation::trace_and_continue(&violation), Action::Continue);
+            assert_eq!(violation::trace_and_panic(&violation), Action::Panic);
+        });
+
+        let events = EVENTS.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        for event in events.iter() {
+            let field = |name| {
+                let (_, value) = event.iter().find(|(field, _)| *field == name).unwrap();
+                value.as_str()
+            };
+            assert_eq!(field("message"), "unexpected NaN or infinity");
+            assert_eq!(field("value"), "inf");
+            assert_eq!(field("checker"), "R64");
+            assert_eq!(field("operation"), "/");
+            assert_eq!(field("operands"), "Some([1.0, 0.0])");
+            assert_eq!(field("file"), file!());
+            assert_eq!(field("line"), format!("{}", line));
+        }
     }
 }
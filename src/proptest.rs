@@ -10,7 +10,12 @@ use proptest::{
 };
 
 use crate::{
-    checkers::{FiniteChecker, NumChecker},
+    checkers::{
+        FiniteChecker, NegativeChecker, NegativeFiniteChecker, NonNegativeChecker,
+        NonNegativeFiniteChecker, NonPositiveChecker, NonPositiveFiniteChecker, NonZeroChecker,
+        NonZeroFiniteChecker, NormalChecker, NumChecker, PositiveChecker, PositiveFiniteChecker,
+        SignedUnitChecker, Strict, UnitChecker,
+    },
     FloatChecker, NoisyFloat,
 };
 
@@ -57,34 +62,104 @@ where
     }
 }
 
-macro_rules! float_any_strategy_impls {
-    ($ftp:ident) => {
-        impl Strategy for Any<$ftp, NumChecker> {
-            type Value = NoisyFloat<$ftp, NumChecker>;
-            type Tree = NoisyFloatValueTree<$ftp::BinarySearch, NumChecker>;
+macro_rules! any_strategy_impl {
+    ($ftp:ident, $checker:ty, $($flag:ident)|+) => {
+        impl Strategy for Any<$ftp, $checker> {
+            type Value = NoisyFloat<$ftp, $checker>;
+            type Tree = NoisyFloatValueTree<$ftp::BinarySearch, $checker>;
             #[inline]
             fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
-                ($ftp::INFINITE
-                    | $ftp::NEGATIVE
-                    | $ftp::POSITIVE
-                    | $ftp::NORMAL
-                    | $ftp::SUBNORMAL
-                    | $ftp::ZERO)
+                ($($ftp::$flag)|+)
                     .new_tree(runner)
                     .map(|t| NoisyFloatValueTree(t, PhantomData))
             }
         }
-        impl Strategy for Any<$ftp, FiniteChecker> {
-            type Value = NoisyFloat<$ftp, FiniteChecker>;
-            type Tree = NoisyFloatValueTree<$ftp::BinarySearch, FiniteChecker>;
+    };
+}
+
+macro_rules! range_strategy_impl {
+    ($ftp:ident, $checker:ty, $lo:literal..=$hi:literal) => {
+        impl Strategy for Any<$ftp, $checker> {
+            type Value = NoisyFloat<$ftp, $checker>;
+            type Tree = NoisyFloatValueTree<$ftp::BinarySearch, $checker>;
             #[inline]
             fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
-                ($ftp::NEGATIVE | $ftp::POSITIVE | $ftp::NORMAL | $ftp::SUBNORMAL | $ftp::ZERO)
+                ($lo as $ftp..=$hi as $ftp)
                     .new_tree(runner)
                     .map(|t| NoisyFloatValueTree(t, PhantomData))
             }
         }
     };
 }
+
+macro_rules! float_any_strategy_impls {
+    ($ftp:ident) => {
+        any_strategy_impl!(
+            $ftp,
+            NumChecker,
+            INFINITE | NEGATIVE | POSITIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        any_strategy_impl!(
+            $ftp,
+            FiniteChecker,
+            NEGATIVE | POSITIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        any_strategy_impl!(
+            $ftp,
+            PositiveChecker,
+            INFINITE | POSITIVE | NORMAL | SUBNORMAL
+        );
+        any_strategy_impl!($ftp, PositiveFiniteChecker, POSITIVE | NORMAL | SUBNORMAL);
+        any_strategy_impl!(
+            $ftp,
+            NonNegativeChecker,
+            INFINITE | POSITIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        any_strategy_impl!(
+            $ftp,
+            NonNegativeFiniteChecker,
+            POSITIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        any_strategy_impl!(
+            $ftp,
+            NegativeChecker,
+            INFINITE | NEGATIVE | NORMAL | SUBNORMAL
+        );
+        any_strategy_impl!($ftp, NegativeFiniteChecker, NEGATIVE | NORMAL | SUBNORMAL);
+        any_strategy_impl!(
+            $ftp,
+            NonPositiveChecker,
+            INFINITE | NEGATIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        any_strategy_impl!(
+            $ftp,
+            NonPositiveFiniteChecker,
+            NEGATIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        any_strategy_impl!(
+            $ftp,
+            NonZeroChecker,
+            INFINITE | NEGATIVE | POSITIVE | NORMAL | SUBNORMAL
+        );
+        any_strategy_impl!(
+            $ftp,
+            NonZeroFiniteChecker,
+            NEGATIVE | POSITIVE | NORMAL | SUBNORMAL
+        );
+        any_strategy_impl!($ftp, NormalChecker, NEGATIVE | POSITIVE | NORMAL | ZERO);
+        any_strategy_impl!(
+            $ftp,
+            Strict<NumChecker>,
+            INFINITE | NEGATIVE | POSITIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        any_strategy_impl!(
+            $ftp,
+            Strict<FiniteChecker>,
+            NEGATIVE | POSITIVE | NORMAL | SUBNORMAL | ZERO
+        );
+        range_strategy_impl!($ftp, UnitChecker, 0.0..=1.0);
+        range_strategy_impl!($ftp, SignedUnitChecker, -1.0..=1.0);
+    };
+}
 float_any_strategy_impls!(f32);
 float_any_strategy_impls!(f64);
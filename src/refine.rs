@@ -0,0 +1,321 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inherent math functions whose result types follow the mathematics.
+//!
+//! These methods take precedence over the methods of the `Float` trait with the same name,
+//! so e.g. `r64(x).abs()` returns a non-negative `NN64`.
+//! This breaks concrete code expecting the type of the operand back,
+//! such as `let y: R64 = x.abs();`, which has to be written as `x.abs().widen()`
+//! or `Float::abs(x)`. Generic code calling the `Float` methods is unaffected.
+//!
+//! `powi` is left to the `Float` trait, since the sign of its result depends on the parity
+//! of the exponent, which is not known at compile time.
+//! `powi_even` takes the exponent as a const parameter instead, and is non-negative.
+
+use crate::{
+    checkers::{
+        FiniteChecker, NegativeChecker, NegativeFiniteChecker, NonNegativeChecker,
+        NonNegativeFiniteChecker, NonPositiveChecker, NonPositiveFiniteChecker, NonZeroChecker,
+        NonZeroFiniteChecker, NumChecker, PositiveChecker, PositiveFiniteChecker,
+        SignedUnitChecker, UnitChecker,
+    },
+    NoisyFloat,
+};
+use num_traits::Float;
+
+impl<F: Float> NoisyFloat<F, FiniteChecker> {
+    /// Computes the absolute value, which is always non-negative.
+    #[inline]
+    pub fn abs(self) -> NoisyFloat<F, NonNegativeFiniteChecker> {
+        NoisyFloat::unchecked_new_generic(self.value.abs())
+    }
+
+    /// Computes `self * self`, which is always non-negative.
+    #[track_caller]
+    #[inline]
+    pub fn square(self) -> NoisyFloat<F, NonNegativeFiniteChecker> {
+        NoisyFloat::new_from_operation(self.value * self.value, "*", &[self.value, self.value])
+    }
+
+    /// Raises the value to the even power `N`, which is always non-negative.
+    ///
+    /// # Examples
+    /// ```
+    /// use noisy_float::prelude::*;
+    ///
+    /// let y: NN64 = r64(-3.0).powi_even::<2>();
+    /// assert_eq!(y, 9.0);
+    /// ```
+    ///
+    /// Odd exponents fail to compile.
+    ///
+    /// ```compile_fail
+    /// use noisy_float::prelude::*;
+    ///
+    /// let _ = r64(-3.0).powi_even::<3>();
+    /// ```
+    #[track_caller]
+    #[inline]
+    pub fn powi_even<const N: i32>(self) -> NoisyFloat<F, NonNegativeFiniteChecker> {
+        const { assert!(N % 2 == 0, "the exponent of `powi_even` must be even") };
+        NoisyFloat::new_from_operation(self.value.powi(N), "powi", &[self.value])
+    }
+
+    /// Computes the length of the hypotenuse, which is always non-negative.
+    #[track_caller]
+    #[inline]
+    pub fn hypot(self, other: Self) -> NoisyFloat<F, NonNegativeFiniteChecker> {
+        NoisyFloat::new_from_operation(
+            self.value.hypot(other.value),
+            "hypot",
+            &[self.value, other.value],
+        )
+    }
+
+    /// Computes `e^self`.
+    ///
+    /// The result is only guaranteed to be non-negative rather than positive,
+    /// since it underflows to zero for values below about -745 for `f64`.
+    /// The `exp` of the non-negative types returns a positive type.
+    #[track_caller]
+    #[inline]
+    pub fn exp(self) -> NoisyFloat<F, NonNegativeFiniteChecker> {
+        NoisyFloat::new_from_operation(self.value.exp(), "exp", &[self.value])
+    }
+
+    /// Computes the sine, which always lies in `[-1, 1]`.
+    #[track_caller]
+    #[inline]
+    pub fn sin(self) -> NoisyFloat<F, SignedUnitChecker> {
+        NoisyFloat::new_from_operation(self.value.sin(), "sin", &[self.value])
+    }
+
+    /// Computes the cosine, which always lies in `[-1, 1]`.
+    #[track_caller]
+    #[inline]
+    pub fn cos(self) -> NoisyFloat<F, SignedUnitChecker> {
+        NoisyFloat::new_from_operation(self.value.cos(), "cos", &[self.value])
+    }
+
+    /// Computes the sine and cosine, which always lie in `[-1, 1]`.
+    #[track_caller]
+    #[inline]
+    pub fn sin_cos(
+        self,
+    ) -> (
+        NoisyFloat<F, SignedUnitChecker>,
+        NoisyFloat<F, SignedUnitChecker>,
+    ) {
+        let (sin, cos) = self.value.sin_cos();
+        (
+            NoisyFloat::new_from_operation(sin, "sin_cos", &[self.value]),
+            NoisyFloat::new_from_operation(cos, "sin_cos", &[self.value]),
+        )
+    }
+}
+
+impl<F: Float> NoisyFloat<F, NumChecker> {
+    /// Computes the absolute value, which is always non-negative.
+    #[inline]
+    pub fn abs(self) -> NoisyFloat<F, NonNegativeChecker> {
+        NoisyFloat::unchecked_new_generic(self.value.abs())
+    }
+
+    /// Computes the length of the hypotenuse, which is always non-negative.
+    #[track_caller]
+    #[inline]
+    pub fn hypot(self, other: Self) -> NoisyFloat<F, NonNegativeChecker> {
+        NoisyFloat::new_from_operation(
+            self.value.hypot(other.value),
+            "hypot",
+            &[self.value, other.value],
+        )
+    }
+
+    /// Computes `e^self`, which is always non-negative.
+    ///
+    /// The result is zero rather than positive for `-Infinity`
+    /// and for values below the underflow range.
+    #[track_caller]
+    #[inline]
+    pub fn exp(self) -> NoisyFloat<F, NonNegativeChecker> {
+        NoisyFloat::new_from_operation(self.value.exp(), "exp", &[self.value])
+    }
+
+    /// Raises the value to the even power `N`, which is always non-negative.
+    #[track_caller]
+    #[inline]
+    pub fn powi_even<const N: i32>(self) -> NoisyFloat<F, NonNegativeChecker> {
+        const { assert!(N % 2 == 0, "the exponent of `powi_even` must be even") };
+        NoisyFloat::new_from_operation(self.value.powi(N), "powi", &[self.value])
+    }
+}
+
+macro_rules! non_negative_impls {
+    ($checker:ty, $log_checker:ty) => {
+        impl<F: Float> NoisyFloat<F, $checker> {
+            /// Computes the square root, which is defined for all values of this type.
+            #[track_caller]
+            #[inline]
+            pub fn sqrt(self) -> Self {
+                Self::new_from_operation(self.value.sqrt(), "sqrt", &[self.value])
+            }
+
+            /// Computes the natural logarithm, which is defined for all values of this type.
+            #[track_caller]
+            #[inline]
+            pub fn ln(self) -> NoisyFloat<F, $log_checker> {
+                NoisyFloat::new_from_operation(self.value.ln(), "ln", &[self.value])
+            }
+
+            /// Computes the base 2 logarithm, which is defined for all values of this type.
+            #[track_caller]
+            #[inline]
+            pub fn log2(self) -> NoisyFloat<F, $log_checker> {
+                NoisyFloat::new_from_operation(self.value.log2(), "log2", &[self.value])
+            }
+
+            /// Computes the base 10 logarithm, which is defined for all values of this type.
+            #[track_caller]
+            #[inline]
+            pub fn log10(self) -> NoisyFloat<F, $log_checker> {
+                NoisyFloat::new_from_operation(self.value.log10(), "log10", &[self.value])
+            }
+
+            /// Computes `e^self`, which is at least one for values of this type,
+            /// but overflows to infinity for large values.
+            #[track_caller]
+            #[inline]
+            pub fn exp(self) -> NoisyFloat<F, PositiveChecker> {
+                NoisyFloat::new_from_operation(self.value.exp(), "exp", &[self.value])
+            }
+        }
+    };
+}
+// The logarithm of zero is -Infinity, and that of +Infinity is +Infinity.
+non_negative_impls!(NonNegativeFiniteChecker, NumChecker);
+non_negative_impls!(NonNegativeChecker, NumChecker);
+non_negative_impls!(PositiveFiniteChecker, FiniteChecker);
+non_negative_impls!(PositiveChecker, NumChecker);
+
+macro_rules! abs_impls {
+    ($($checker:ty => $abs_checker:ty,)+) => {
+        $(
+            impl<F: Float> NoisyFloat<F, $checker> {
+                /// Computes the absolute value, which is never negative
+                /// and only zero if this type admits zero.
+                #[inline]
+                pub fn abs(self) -> NoisyFloat<F, $abs_checker> {
+                    NoisyFloat::unchecked_new_generic(self.value.abs())
+                }
+            }
+        )+
+    };
+}
+abs_impls!(
+    NegativeFiniteChecker => PositiveFiniteChecker,
+    NegativeChecker => PositiveChecker,
+    NonPositiveFiniteChecker => NonNegativeFiniteChecker,
+    NonPositiveChecker => NonNegativeChecker,
+    NonZeroFiniteChecker => PositiveFiniteChecker,
+    NonZeroChecker => PositiveChecker,
+);
+
+macro_rules! non_positive_impls {
+    ($($checker:ty),+) => {
+        $(
+            impl<F: Float> NoisyFloat<F, $checker> {
+                /// Computes `e^self`, which always lies in `[0, 1]` for values of this type.
+                #[track_caller]
+                #[inline]
+                pub fn exp(self) -> NoisyFloat<F, UnitChecker> {
+                    NoisyFloat::new_from_operation(self.value.exp(), "exp", &[self.value])
+                }
+            }
+        )+
+    };
+}
+non_positive_impls!(
+    NegativeFiniteChecker,
+    NegativeChecker,
+    NonPositiveFiniteChecker,
+    NonPositiveChecker
+);
+
+macro_rules! recip_impls {
+    ($($checker:ty => $recip_checker:ty,)+) => {
+        $(
+            impl<F: Float> NoisyFloat<F, $checker> {
+                /// Computes `1 / self`, which keeps the sign of this type
+                /// but overflows to infinity for subnormal values.
+                #[track_caller]
+                #[inline]
+                pub fn recip(self) -> NoisyFloat<F, $recip_checker> {
+                    NoisyFloat::new_from_operation(self.value.recip(), "recip", &[self.value])
+                }
+            }
+        )+
+    };
+}
+recip_impls!(
+    PositiveFiniteChecker => PositiveChecker,
+    NegativeFiniteChecker => NegativeChecker,
+    NonZeroFiniteChecker => NonZeroChecker,
+);
+
+impl<F: Float> NoisyFloat<F, SignedUnitChecker> {
+    /// Computes the arcsine, which is defined for all values of this type.
+    #[track_caller]
+    #[inline]
+    pub fn asin(self) -> NoisyFloat<F, FiniteChecker> {
+        NoisyFloat::new_from_operation(self.value.asin(), "asin", &[self.value])
+    }
+
+    /// Computes the arccosine, which is defined for all values of this type
+    /// and always non-negative.
+    #[track_caller]
+    #[inline]
+    pub fn acos(self) -> NoisyFloat<F, NonNegativeFiniteChecker> {
+        NoisyFloat::new_from_operation(self.value.acos(), "acos", &[self.value])
+    }
+}
+
+impl<F: Float> NoisyFloat<F, UnitChecker> {
+    /// Returns `1 - self`, e.g. the probability of the complementary event,
+    /// which always lies in `[0, 1]`.
+    ///
+    /// # Examples
+    /// ```
+    /// use noisy_float::prelude::*;
+    ///
+    /// let p = unit64(0.8);
+    /// let q = unit64(0.5);
+    /// let both: U64 = p * q;
+    /// let neither: U64 = p.complement() * q.complement();
+    ///
+    /// assert_eq!(both, 0.4);
+    /// assert!(neither < both);
+    /// ```
+    #[inline]
+    pub fn complement(self) -> Self {
+        NoisyFloat::unchecked_new_generic(F::one() - self.value)
+    }
+
+    /// Raises the value to a non-negative power, which always lies in `[0, 1]`.
+    #[inline]
+    pub fn powf(self, n: NoisyFloat<F, NonNegativeFiniteChecker>) -> Self {
+        NoisyFloat::unchecked_new_generic(self.value.powf(n.value))
+    }
+}
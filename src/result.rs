@@ -0,0 +1,361 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{checkers::Join, FloatChecker, InvalidFloat, NoisyFloat, Operation};
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+use num_traits::Float;
+
+/// The result of a computation with `NoisyFloat` values that never panics.
+///
+/// The operators and the methods named after those of the `Float` trait
+/// work as they do on `NoisyFloat`, but instead of panicking on an invalid result,
+/// the first `InvalidFloat` produced is kept and propagated through the rest
+/// of the computation, much like NaN would be, but with its reason and the operation
+/// that produced it. `into_result` returns the outcome of the whole computation.
+/// As with `NoisyFloat`, the other operand may be a `NoisyFloat` or `NoisyResult`
+/// with another checker, a float, or a small integer, on either side.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{checkers::FiniteChecker, prelude::*, InvalidReason, NoisyResult};
+///
+/// fn solve(a: R64, b: R64, c: R64) -> NoisyResult<f64, FiniteChecker> {
+///     let discriminant = NoisyResult::from(b) * b - a * c * 4.0;
+///     (-b + discriminant.sqrt()) / (a * 2.0)
+/// }
+///
+/// assert_eq!(solve(r64(1.0), r64(0.0), r64(-4.0)).into_result(), Ok(r64(2.0)));
+///
+/// let error = solve(r64(1.0), r64(0.0), r64(4.0)).into_result().unwrap_err();
+/// assert_eq!(error.reason(), InvalidReason::Nan);
+/// assert_eq!(error.to_string(), "invalid value NaN (NaN) produced by sqrt(-16)");
+/// ```
+pub struct NoisyResult<F: Float, C: FloatChecker<F>> {
+    result: Result<NoisyFloat<F, C>, InvalidFloat<F>>,
+}
+
+impl<F: Float, C: FloatChecker<F>> NoisyResult<F, C> {
+    /// Constructs a `NoisyResult` with the given value,
+    /// which holds an `InvalidFloat` if the value is invalid.
+    #[inline]
+    pub fn new(value: F) -> Self {
+        Self::from_result(NoisyFloat::try_new_checked(value))
+    }
+
+    #[inline]
+    fn from_result(result: Result<NoisyFloat<F, C>, InvalidFloat<F>>) -> Self {
+        NoisyResult { result }
+    }
+
+    /// Returns the valid value, or the first `InvalidFloat` produced by the computation.
+    #[inline]
+    pub fn into_result(self) -> Result<NoisyFloat<F, C>, InvalidFloat<F>> {
+        self.result
+    }
+
+    /// Returns the valid value, or `None` if an invalid value was produced.
+    #[inline]
+    pub fn ok(self) -> Option<NoisyFloat<F, C>> {
+        self.result.ok()
+    }
+
+    /// Returns `true` if no invalid value was produced.
+    #[inline]
+    pub fn is_ok(&self) -> bool {
+        self.result.is_ok()
+    }
+
+    /// Returns `true` if an invalid value was produced.
+    #[inline]
+    pub fn is_err(&self) -> bool {
+        self.result.is_err()
+    }
+
+    #[inline]
+    fn raw_op(self, rhs: F, name: &'static str, op: impl FnOnce(F, F) -> F) -> Self {
+        Self::from_result(
+            self.result
+                .and_then(|lhs| checked_op(lhs.raw(), rhs, name, op)),
+        )
+    }
+}
+
+/// Applies a binary operator to raw values,
+/// recording the operation in the `InvalidFloat` if the result is invalid.
+#[inline]
+fn checked_op<F: Float, C: FloatChecker<F>>(
+    lhs: F,
+    rhs: F,
+    name: &'static str,
+    op: impl FnOnce(F, F) -> F,
+) -> Result<NoisyFloat<F, C>, InvalidFloat<F>> {
+    NoisyFloat::try_new_checked(op(lhs, rhs))
+        .map_err(|error| error.with_operation(Operation::new(name, &[lhs, rhs])))
+}
+
+macro_rules! result_methods {
+    ($($method:ident => $checked:ident($($arg:ident : $argtp:tt),*);)+) => {
+        impl<F: Float, C: FloatChecker<F>> NoisyResult<F, C> {
+            $(
+                #[doc = concat!(
+                    "Computes `", stringify!($method), "`, ",
+                    "keeping the first invalid value instead of panicking."
+                )]
+                #[inline]
+                pub fn $method(self $(, $arg: $argtp)*) -> Self {
+                    Self::from_result(self.result.and_then(|value| {
+                        value.$checked($(result_methods!(@arg $arg: $argtp)),*)
+                    }))
+                }
+            )+
+        }
+    };
+    (@arg $arg:ident : Self) => { $arg.result? };
+    (@arg $arg:ident : $argtp:tt) => { $arg };
+}
+
+result_methods! {
+    floor => checked_floor();
+    ceil => checked_ceil();
+    round => checked_round();
+    trunc => checked_trunc();
+    fract => checked_fract();
+    abs => checked_abs();
+    signum => checked_signum();
+    mul_add => checked_mul_add(a: Self, b: Self);
+    recip => checked_recip();
+    powi => checked_powi(n: i32);
+    powf => checked_powf(n: Self);
+    sqrt => checked_sqrt();
+    exp => checked_exp();
+    exp2 => checked_exp2();
+    ln => checked_ln();
+    log => checked_log(base: Self);
+    log2 => checked_log2();
+    log10 => checked_log10();
+    abs_sub => checked_abs_sub(other: Self);
+    cbrt => checked_cbrt();
+    hypot => checked_hypot(other: Self);
+    sin => checked_sin();
+    cos => checked_cos();
+    tan => checked_tan();
+    asin => checked_asin();
+    acos => checked_acos();
+    atan => checked_atan();
+    atan2 => checked_atan2(other: Self);
+    exp_m1 => checked_exp_m1();
+    ln_1p => checked_ln_1p();
+    sinh => checked_sinh();
+    cosh => checked_cosh();
+    tanh => checked_tanh();
+    asinh => checked_asinh();
+    acosh => checked_acosh();
+    atanh => checked_atanh();
+    to_degrees => checked_to_degrees();
+    to_radians => checked_to_radians();
+}
+
+impl<F: Float, C: FloatChecker<F>> NoisyResult<F, C> {
+    /// Computes `sin_cos`, keeping the first invalid value instead of panicking.
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        match self.result.and_then(NoisyFloat::checked_sin_cos) {
+            Ok((sin, cos)) => (sin.into(), cos.into()),
+            Err(error) => (Self::from_result(Err(error)), Self::from_result(Err(error))),
+        }
+    }
+
+    /// Returns the maximum of two values, keeping the first invalid value.
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self::from_result(self.result.and_then(|value| Ok(value.max(other.result?))))
+    }
+
+    /// Returns the minimum of two values, keeping the first invalid value.
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self::from_result(self.result.and_then(|value| Ok(value.min(other.result?))))
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> Clone for NoisyResult<F, C> {
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> Copy for NoisyResult<F, C> {}
+
+impl<F: Float + fmt::Debug, C: FloatChecker<F>> fmt::Debug for NoisyResult<F, C> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Debug::fmt(&self.result, f)
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> From<NoisyFloat<F, C>> for NoisyResult<F, C> {
+    #[inline]
+    fn from(value: NoisyFloat<F, C>) -> Self {
+        Self::from_result(Ok(value))
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> From<Result<NoisyFloat<F, C>, InvalidFloat<F>>>
+    for NoisyResult<F, C>
+{
+    #[inline]
+    fn from(result: Result<NoisyFloat<F, C>, InvalidFloat<F>>) -> Self {
+        Self::from_result(result)
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> Neg for NoisyResult<F, C> {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self::from_result(self.result.and_then(NoisyFloat::checked_neg))
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> Neg for &NoisyResult<F, C> {
+    type Output = NoisyResult<F, C>;
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+macro_rules! result_op_impls {
+    ($trid:ident, $fnid:ident, $symbol:literal, $assign_trid:ident, $assign_fnid:ident) => {
+        op_impl!(
+            (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+            $trid, (NoisyResult<F, C>, NoisyResult<F, C2>),
+            $fnid(self, rhs: NoisyResult<F, C2>) -> NoisyResult<F, J> {
+                NoisyResult::from_result(self.result.and_then(|lhs| {
+                    checked_op(lhs.raw(), rhs.result?.raw(), $symbol, $trid::$fnid)
+                }))
+            }
+        );
+        op_impl!(
+            (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+            $trid, (NoisyResult<F, C>, NoisyFloat<F, C2>),
+            $fnid(self, rhs: NoisyFloat<F, C2>) -> NoisyResult<F, J> {
+                NoisyResult::from_result(self.result.and_then(|lhs| {
+                    checked_op(lhs.raw(), rhs.raw(), $symbol, $trid::$fnid)
+                }))
+            }
+        );
+        op_impl!(
+            (F: Float, C: FloatChecker<F> + Join<C2, Output = J>, C2: FloatChecker<F>, J: FloatChecker<F>),
+            $trid, (NoisyFloat<F, C>, NoisyResult<F, C2>),
+            $fnid(self, rhs: NoisyResult<F, C2>) -> NoisyResult<F, J> {
+                NoisyResult::from_result(rhs.result.and_then(|rhs| {
+                    checked_op(self.raw(), rhs.raw(), $symbol, $trid::$fnid)
+                }))
+            }
+        );
+        op_impl!(
+            (F: Float, C: FloatChecker<F>), $trid, (NoisyResult<F, C>, F),
+            $fnid(self, rhs: F) -> NoisyResult<F, C> {
+                self.raw_op(rhs, $symbol, $trid::$fnid)
+            }
+        );
+
+        impl<F: Float, C: FloatChecker<F>> $assign_trid for NoisyResult<F, C> {
+            #[inline]
+            fn $assign_fnid(&mut self, rhs: Self) {
+                *self = Self::from_result(self.result.and_then(|lhs| {
+                    checked_op(lhs.raw(), rhs.result?.raw(), $symbol, $trid::$fnid)
+                }));
+            }
+        }
+
+        impl<F: Float, C: FloatChecker<F>> $assign_trid<NoisyFloat<F, C>> for NoisyResult<F, C> {
+            #[inline]
+            fn $assign_fnid(&mut self, rhs: NoisyFloat<F, C>) {
+                *self = self.raw_op(rhs.raw(), $symbol, $trid::$fnid);
+            }
+        }
+
+        impl<F: Float, C: FloatChecker<F>> $assign_trid<F> for NoisyResult<F, C> {
+            #[inline]
+            fn $assign_fnid(&mut self, rhs: F) {
+                *self = self.raw_op(rhs, $symbol, $trid::$fnid);
+            }
+        }
+    };
+}
+result_op_impls!(Add, add, "+", AddAssign, add_assign);
+result_op_impls!(Sub, sub, "-", SubAssign, sub_assign);
+result_op_impls!(Mul, mul, "*", MulAssign, mul_assign);
+result_op_impls!(Div, div, "/", DivAssign, div_assign);
+result_op_impls!(Rem, rem, "%", RemAssign, rem_assign);
+
+macro_rules! result_left_op_impls {
+    ($ftp:ty) => {
+        result_left_op_impls!(@op $ftp, $ftp, Add, add, "+");
+        result_left_op_impls!(@op $ftp, $ftp, Sub, sub, "-");
+        result_left_op_impls!(@op $ftp, $ftp, Mul, mul, "*");
+        result_left_op_impls!(@op $ftp, $ftp, Div, div, "/");
+        result_left_op_impls!(@op $ftp, $ftp, Rem, rem, "%");
+    };
+    (@op $ftp:ty, $ltp:ty, $trid:ident, $fnid:ident, $symbol:literal) => {
+        op_impl!(
+            (C: FloatChecker<$ftp>), $trid, ($ltp, NoisyResult<$ftp, C>),
+            $fnid(self, rhs: NoisyResult<$ftp, C>) -> NoisyResult<$ftp, C> {
+                // `self` is either a number or a reference to one.
+                let lhs: &$ltp = &self;
+                let lhs = <$ftp as From<$ltp>>::from(*lhs);
+                NoisyResult::from_result(rhs.result.and_then(|rhs| {
+                    checked_op(lhs, rhs.raw(), $symbol, $trid::$fnid)
+                }))
+            }
+        );
+    };
+}
+result_left_op_impls!(f32);
+result_left_op_impls!(f64);
+
+macro_rules! result_int_op_impls {
+    ($ftp:ty, $itp:ty) => {
+        result_int_op_impls!(@op $ftp, $itp, Add, add, "+");
+        result_int_op_impls!(@op $ftp, $itp, Sub, sub, "-");
+        result_int_op_impls!(@op $ftp, $itp, Mul, mul, "*");
+        result_int_op_impls!(@op $ftp, $itp, Div, div, "/");
+        result_int_op_impls!(@op $ftp, $itp, Rem, rem, "%");
+    };
+    (@op $ftp:ty, $itp:ty, $trid:ident, $fnid:ident, $symbol:literal) => {
+        op_impl!(
+            (C: FloatChecker<$ftp>), $trid, (NoisyResult<$ftp, C>, $itp),
+            $fnid(self, rhs: $itp) -> NoisyResult<$ftp, C> {
+                self.raw_op(<$ftp as From<$itp>>::from(rhs), $symbol, $trid::$fnid)
+            }
+        );
+        result_left_op_impls!(@op $ftp, $itp, $trid, $fnid, $symbol);
+    };
+}
+result_int_op_impls!(f32, i8);
+result_int_op_impls!(f32, i16);
+result_int_op_impls!(f32, u8);
+result_int_op_impls!(f32, u16);
+result_int_op_impls!(f64, i8);
+result_int_op_impls!(f64, i16);
+result_int_op_impls!(f64, i32);
+result_int_op_impls!(f64, u8);
+result_int_op_impls!(f64, u16);
+result_int_op_impls!(f64, u32);
@@ -0,0 +1,178 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Configuration of the `Sampled` checkers.
+//!
+//! A `Sampled` checker only checks 1 in N of the values it is asked to check,
+//! in all builds, and never panics.
+//! The first violation found at each call site is passed to the callback
+//! registered with `set_callback`; later ones at the same call site are ignored.
+//! Up to 256 call sites are remembered. Once that many have reported a violation,
+//! violations at further call sites are passed to the callback every time they are found,
+//! until `reset_sites` is called.
+//! The rate and the callback are stored in atomics, so this works with no_std.
+//!
+//! # Examples
+//! ```
+//! use core::sync::atomic::{AtomicUsize, Ordering};
+//! use noisy_float::{
+//!     checkers::{FiniteChecker, Sampled},
+//!     sampling,
+//!     violation::Violation,
+//!     NoisyFloat,
+//! };
+//!
+//! type ProbedR64 = NoisyFloat<f64, Sampled<FiniteChecker>>;
+//!
+//! static REPORTED: AtomicUsize = AtomicUsize::new(0);
+//!
+//! fn report(violation: &Violation) {
+//!     assert_eq!(violation.checker(), "R64");
+//!     REPORTED.fetch_add(1, Ordering::Relaxed);
+//! }
+//!
+//! sampling::set_callback(report);
+//! sampling::set_rate(1);
+//! for _ in 0..10 {
+//!     let _ = ProbedR64::new(1.0) / 0.0;
+//! }
+//! assert_eq!(REPORTED.load(Ordering::Relaxed), 1);
+//! ```
+
+use crate::{violation::Violation, FloatChecker, Operation};
+use core::{
+    mem,
+    panic::Location,
+    ptr,
+    sync::atomic::{AtomicPtr, AtomicU32, Ordering},
+};
+use num_traits::Float;
+
+/// The default sampling rate, checking 1 in 1024 values.
+pub const DEFAULT_RATE: u32 = 1024;
+
+/// A callback called with the first violation found at each call site.
+pub type Callback = fn(&Violation);
+
+static RATE: AtomicU32 = AtomicU32::new(DEFAULT_RATE);
+static COUNTER: AtomicU32 = AtomicU32::new(0);
+static CALLBACK: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// The number of call sites whose violations are remembered.
+/// Violations at further call sites are reported every time they are found.
+const SITES: usize = 256;
+
+static REPORTED_SITES: [AtomicPtr<Location<'static>>; SITES] =
+    [const { AtomicPtr::new(ptr::null_mut()) }; SITES];
+
+/// Sets the sampling rate, so that 1 in `rate` values is checked.
+///
+/// A rate of 1 checks every value, and a rate of 0 disables checking.
+#[inline]
+pub fn set_rate(rate: u32) {
+    RATE.store(rate, Ordering::Relaxed);
+}
+
+/// Returns the sampling rate.
+#[inline]
+pub fn rate() -> u32 {
+    RATE.load(Ordering::Relaxed)
+}
+
+/// Registers the callback, replacing the previous one.
+#[inline]
+pub fn set_callback(callback: Callback) {
+    CALLBACK.store(callback as *mut (), Ordering::Release);
+}
+
+/// Removes the callback, so that violations are no longer reported.
+#[inline]
+pub fn reset_callback() {
+    CALLBACK.store(ptr::null_mut(), Ordering::Release);
+}
+
+/// Returns the registered callback, if any.
+#[inline]
+pub fn callback() -> Option<Callback> {
+    let callback = CALLBACK.load(Ordering::Acquire);
+    if callback.is_null() {
+        None
+    } else {
+        // SAFETY: the only non-null values stored are `Callback` pointers cast by `set_callback`,
+        // which survive the round trip through a data pointer as in `violation::handler`.
+        Some(unsafe { mem::transmute::<*mut (), Callback>(callback) })
+    }
+}
+
+/// Forgets the call sites already reported,
+/// so that their next violation is reported again.
+pub fn reset_sites() {
+    for site in REPORTED_SITES.iter() {
+        site.store(ptr::null_mut(), Ordering::Relaxed);
+    }
+}
+
+/// Returns `true` if the next value should be checked.
+#[inline]
+pub(crate) fn sample() -> bool {
+    match rate() {
+        0 => false,
+        1 => true,
+        rate => COUNTER.fetch_add(1, Ordering::Relaxed) % rate == 0,
+    }
+}
+
+/// Returns `true` if no violation was reported yet at the call site.
+///
+/// Call sites are keyed on their file, line and column, like in the `stats` module,
+/// since the same call site is not guaranteed to have a unique `Location`.
+fn first_at(location: &'static Location<'static>) -> bool {
+    let site = location as *const Location<'static> as *mut Location<'static>;
+    let start = (location.line() as usize)
+        .wrapping_mul(31)
+        .wrapping_add(location.column() as usize)
+        % SITES;
+    for i in 0..SITES {
+        match REPORTED_SITES[(start + i) % SITES].compare_exchange(
+            ptr::null_mut(),
+            site,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => return true,
+            // SAFETY: the only non-null values stored are `&'static Location` pointers.
+            Err(reported) if same_site(unsafe { &*reported }, location) => return false,
+            Err(_) => {}
+        }
+    }
+    true
+}
+
+#[inline]
+fn same_site(a: &Location, b: &Location) -> bool {
+    (a.file(), a.line(), a.column()) == (b.file(), b.line(), b.column())
+}
+
+/// Passes a sampled value rejected by the checker `C` to the callback,
+/// if it is the first violation at the call site.
+#[cold]
+#[track_caller]
+pub(crate) fn report<F: Float, C: FloatChecker<F>>(value: F, operation: Option<Operation<F>>) {
+    if let Some(callback) = callback() {
+        let location = Location::caller();
+        if first_at(location) {
+            callback(&Violation::capture::<F, C>(value, operation));
+        }
+    }
+}
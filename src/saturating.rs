@@ -0,0 +1,209 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::{checkers::Clamp, NoisyFloat};
+use core::{
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+};
+use num_traits::Float;
+
+/// Saturating arithmetic on a `NoisyFloat`.
+///
+/// The operators and the methods named after those of the `Float` trait clamp
+/// an invalid result to the nearest valid value using `NoisyFloat::new_clamped`,
+/// instead of panicking: an overflow to infinity becomes `max_value` or `min_value`,
+/// and a result outside of a range becomes the nearest bound.
+/// Only checkers implementing `Clamp` are supported.
+///
+/// A NaN result, such as that of `0 / 0` or the square root of a negative number,
+/// cannot be clamped and panics, even in optimized builds.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{prelude::*, ranged, Saturating};
+///
+/// type Gain = ranged!(f64, 0.0 <= x <= 1.0);
+///
+/// let level = Saturating(Gain::new(0.75));
+/// assert_eq!((level + 0.5).0, 1.0);
+/// assert_eq!((level - 2.0).0, 0.0);
+///
+/// let huge = Saturating(r64(f64::MAX));
+/// assert_eq!((huge * 2.0).0, f64::MAX);
+/// assert_eq!((-huge).exp().0, 0.0);
+/// ```
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct Saturating<T>(pub T);
+
+impl<T: fmt::Debug> fmt::Debug for Saturating<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Saturating<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<F: Float, C: Clamp<F>> Saturating<NoisyFloat<F, C>> {
+    /// Constructs a `Saturating` value with the valid value nearest to the given value.
+    ///
+    /// # Panics
+    /// Panics if the value is NaN, even in optimized builds.
+    #[track_caller]
+    #[inline]
+    pub fn new(value: F) -> Self {
+        Saturating(NoisyFloat::new_clamped(value))
+    }
+
+    /// Returns the underlying float value.
+    #[inline]
+    pub fn raw(self) -> F {
+        self.0.raw()
+    }
+}
+
+macro_rules! saturating_methods {
+    ($($method:ident($($arg:ident : $argtp:tt),*);)+) => {
+        impl<F: Float, C: Clamp<F>> Saturating<NoisyFloat<F, C>> {
+            $(
+                #[doc = concat!(
+                    "Computes `", stringify!($method), "`, ",
+                    "clamping the result to the nearest valid value."
+                )]
+                #[track_caller]
+                #[inline]
+                pub fn $method(self $(, $arg: $argtp)*) -> Self {
+                    Self::new(self.raw().$method($(saturating_methods!(@raw $arg: $argtp)),*))
+                }
+            )+
+        }
+    };
+    (@raw $arg:ident : Self) => { $arg.raw() };
+    (@raw $arg:ident : $argtp:tt) => { $arg };
+}
+
+saturating_methods! {
+    floor();
+    ceil();
+    round();
+    trunc();
+    fract();
+    abs();
+    signum();
+    mul_add(a: Self, b: Self);
+    recip();
+    powi(n: i32);
+    powf(n: Self);
+    sqrt();
+    exp();
+    exp2();
+    ln();
+    log(base: Self);
+    log2();
+    log10();
+    abs_sub(other: Self);
+    cbrt();
+    hypot(other: Self);
+    sin();
+    cos();
+    tan();
+    asin();
+    acos();
+    atan();
+    atan2(other: Self);
+    exp_m1();
+    ln_1p();
+    sinh();
+    cosh();
+    tanh();
+    asinh();
+    acosh();
+    atanh();
+    to_degrees();
+    to_radians();
+}
+
+impl<F: Float, C: Clamp<F>> Saturating<NoisyFloat<F, C>> {
+    /// Computes `sin_cos`, clamping the results to the nearest valid values.
+    #[track_caller]
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        let (sin, cos) = self.raw().sin_cos();
+        (Self::new(sin), Self::new(cos))
+    }
+}
+
+impl<F: Float, C: Clamp<F>> Neg for Saturating<NoisyFloat<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.raw())
+    }
+}
+
+impl<F: Float, C: Clamp<F>> Neg for &Saturating<NoisyFloat<F, C>> {
+    type Output = Saturating<NoisyFloat<F, C>>;
+    #[track_caller]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+macro_rules! saturating_op_impls {
+    ($trid:ident, $fnid:ident, $assign_trid:ident, $assign_fnid:ident) => {
+        op_impl!(
+            (F: Float, C: Clamp<F>), $trid,
+            (Saturating<NoisyFloat<F, C>>, Saturating<NoisyFloat<F, C>>),
+            $fnid(self, rhs: Saturating<NoisyFloat<F, C>>) -> Saturating<NoisyFloat<F, C>> {
+                Saturating::new(self.raw().$fnid(rhs.raw()))
+            }
+        );
+        op_impl!(
+            (F: Float, C: Clamp<F>), $trid, (Saturating<NoisyFloat<F, C>>, F),
+            $fnid(self, rhs: F) -> Saturating<NoisyFloat<F, C>> {
+                Saturating::new(self.raw().$fnid(rhs))
+            }
+        );
+
+        impl<F: Float, C: Clamp<F>> $assign_trid for Saturating<NoisyFloat<F, C>> {
+            #[track_caller]
+            #[inline]
+            fn $assign_fnid(&mut self, rhs: Self) {
+                *self = $trid::$fnid(*self, rhs);
+            }
+        }
+
+        impl<F: Float, C: Clamp<F>> $assign_trid<F> for Saturating<NoisyFloat<F, C>> {
+            #[track_caller]
+            #[inline]
+            fn $assign_fnid(&mut self, rhs: F) {
+                *self = $trid::$fnid(*self, rhs);
+            }
+        }
+    };
+}
+saturating_op_impls!(Add, add, AddAssign, add_assign);
+saturating_op_impls!(Sub, sub, SubAssign, sub_assign);
+saturating_op_impls!(Mul, mul, MulAssign, mul_assign);
+saturating_op_impls!(Div, div, DivAssign, div_assign);
+saturating_op_impls!(Rem, rem, RemAssign, rem_assign);
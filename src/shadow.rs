@@ -0,0 +1,392 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Shadow computations in `f64`, to find where precision is lost.
+//!
+//! A `Shadowed` value carries an `f64` shadow alongside its `NoisyFloat`.
+//! The operators and the methods named after those of the `Float` trait
+//! compute the result both in the precision of the `NoisyFloat`, which is checked
+//! by its checker as usual, and in `f64` from the shadows of the operands.
+//! When the relative error between the two exceeds the tolerance set with
+//! `set_tolerance`, the value is reported to the violation handler, like a value
+//! rejected by a checker, and the default is to panic.
+//!
+//! Errors are only reported by the operation where they first exceed the tolerance,
+//! and not by the later operations on the inaccurate value,
+//! so that the report points at the source of the loss.
+//! Shadowing `f64` values only finds errors larger than those of `f64` itself.
+//!
+//! # Examples
+//! ```
+//! use noisy_float::{prelude::*, shadow::Shadowed, violation::{self, Action}};
+//!
+//! violation::set_handler(|violation| {
+//!     assert_eq!(violation.checker(), "R32");
+//!     assert_eq!(violation.operation().unwrap().name(), "-");
+//!     Action::Continue
+//! });
+//!
+//! let big = Shadowed::<R32>::new(1e8);
+//! let sum = big + 1.5;
+//! assert_eq!(sum.raw(), 1e8);
+//! assert_eq!(sum.shadow(), 100_000_001.5);
+//!
+//! // the cancellation exposes the rounding of the sum
+//! let difference = sum - big;
+//! assert_eq!(difference.raw(), 0.0);
+//! assert_eq!(difference.shadow(), 1.5);
+//! violation::reset_handler();
+//! ```
+
+use crate::{violation, FloatChecker, NoisyFloat, Operation};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
+    sync::atomic::{self, AtomicU64},
+};
+use num_traits::Float;
+
+/// The default tolerance, a relative error of `1e-4`,
+/// about 10 of the 24 bits of the significand of an `f32`.
+pub const DEFAULT_TOLERANCE: f64 = 1e-4;
+
+static TOLERANCE: AtomicU64 = AtomicU64::new(DEFAULT_TOLERANCE.to_bits());
+
+/// Sets the largest relative error that is not reported.
+#[inline]
+pub fn set_tolerance(tolerance: f64) {
+    TOLERANCE.store(tolerance.to_bits(), atomic::Ordering::Relaxed);
+}
+
+/// Returns the largest relative error that is not reported.
+#[inline]
+pub fn tolerance() -> f64 {
+    f64::from_bits(TOLERANCE.load(atomic::Ordering::Relaxed))
+}
+
+/// Returns the relative error of the value to the shadow.
+///
+/// This is zero if they are equal, including if both are NaN,
+/// and infinite if the shadow is zero and the value is not.
+fn relative_error(value: f64, shadow: f64) -> f64 {
+    if value == shadow || (value.is_nan() && shadow.is_nan()) {
+        0.0
+    } else {
+        ((value - shadow) / shadow).abs()
+    }
+}
+
+/// Returns `true` if the relative error is within the tolerance,
+/// which is never the case for a NaN error.
+fn within_tolerance(error: f64) -> bool {
+    error <= tolerance()
+}
+
+/// A `NoisyFloat` with an `f64` shadow, computed alongside it to measure the loss of precision.
+///
+/// See the `shadow` module for how errors are reported.
+/// Comparisons only use the `NoisyFloat`, not the shadow.
+///
+/// # Examples
+/// ```
+/// use noisy_float::{prelude::*, shadow::Shadowed};
+///
+/// let third = Shadowed::<R32>::new(1.0) / 3.0;
+/// assert_eq!(third.raw(), 1.0 / 3.0);
+/// assert_eq!(third.shadow(), 1.0 / 3.0);
+/// assert!(third.relative_error() < 1e-7);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct Shadowed<T> {
+    value: T,
+    shadow: f64,
+}
+
+impl<F: Float, C: FloatChecker<F>> Shadowed<NoisyFloat<F, C>> {
+    /// Constructs a `Shadowed` value, whose shadow is the value converted to `f64`.
+    ///
+    /// Uses the `FloatChecker` to assert that the value is valid.
+    #[track_caller]
+    #[inline]
+    pub fn new(value: F) -> Self {
+        Self::from(NoisyFloat::new(value))
+    }
+
+    /// Returns the underlying float value.
+    #[inline]
+    pub fn raw(self) -> F {
+        self.value.raw()
+    }
+
+    /// Returns the `NoisyFloat`, dropping the shadow.
+    #[inline]
+    pub fn value(self) -> NoisyFloat<F, C> {
+        self.value
+    }
+
+    /// Returns the shadow, the value computed in `f64`.
+    #[inline]
+    pub fn shadow(self) -> f64 {
+        self.shadow
+    }
+
+    /// Returns the relative error of the value to its shadow.
+    #[inline]
+    pub fn relative_error(self) -> f64 {
+        relative_error(self.raw().to_f64().unwrap_or(f64::NAN), self.shadow)
+    }
+
+    /// Constructs the result of an operation from its value and shadow,
+    /// reporting it if its relative error exceeds the tolerance
+    /// while those of the `Shadowed` operands do not.
+    #[track_caller]
+    #[inline]
+    fn compute(
+        value: F,
+        shadow: f64,
+        name: &'static str,
+        operands: &[F],
+        shadowed: &[Self],
+    ) -> Self {
+        let mut result = Shadowed {
+            value: NoisyFloat::new_from_operation(value, name, operands),
+            shadow,
+        };
+        if !within_tolerance(result.relative_error())
+            && shadowed
+                .iter()
+                .all(|operand| within_tolerance(operand.relative_error()))
+        {
+            let operation = Operation::new(name, operands);
+            let value = violation::report_inaccuracy::<F, C>(result.value.raw(), shadow, operation);
+            result.value = NoisyFloat::unchecked_new_generic(value);
+        }
+        result
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> From<NoisyFloat<F, C>> for Shadowed<NoisyFloat<F, C>> {
+    #[inline]
+    fn from(value: NoisyFloat<F, C>) -> Self {
+        Shadowed {
+            value,
+            shadow: value.raw().to_f64().unwrap_or(f64::NAN),
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for Shadowed<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq> Eq for Shadowed<T> {}
+
+impl<T: PartialOrd> PartialOrd for Shadowed<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord> Ord for Shadowed<T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Shadowed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        f.debug_struct("Shadowed")
+            .field("value", &self.value)
+            .field("shadow", &self.shadow)
+            .finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Shadowed<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+macro_rules! shadowed_methods {
+    ($($method:ident($($arg:ident : $argtp:tt),*);)+) => {
+        impl<F: Float, C: FloatChecker<F>> Shadowed<NoisyFloat<F, C>> {
+            $(
+                #[doc = concat!(
+                    "Computes `", stringify!($method), "` on the value and on the shadow, ",
+                    "reporting a loss of precision."
+                )]
+                #[track_caller]
+                #[inline]
+                pub fn $method(self $(, $arg: $argtp)*) -> Self {
+                    Self::compute(
+                        Float::$method(self.raw() $(, shadowed_methods!(@raw $arg: $argtp))*),
+                        Float::$method(self.shadow $(, shadowed_methods!(@shadow $arg: $argtp))*),
+                        stringify!($method),
+                        &[self.raw() $(, shadowed_methods!(@operand $arg: $argtp))*],
+                        &[self $(, $arg)*],
+                    )
+                }
+            )+
+        }
+    };
+    (@raw $arg:ident : Self) => { $arg.raw() };
+    (@shadow $arg:ident : Self) => { $arg.shadow };
+    (@operand $arg:ident : Self) => { $arg.raw() };
+}
+
+shadowed_methods! {
+    floor();
+    ceil();
+    round();
+    trunc();
+    fract();
+    abs();
+    signum();
+    mul_add(a: Self, b: Self);
+    recip();
+    powf(n: Self);
+    sqrt();
+    exp();
+    exp2();
+    ln();
+    log(base: Self);
+    log2();
+    log10();
+    abs_sub(other: Self);
+    cbrt();
+    hypot(other: Self);
+    sin();
+    cos();
+    tan();
+    asin();
+    acos();
+    atan();
+    atan2(other: Self);
+    exp_m1();
+    ln_1p();
+    sinh();
+    cosh();
+    tanh();
+    asinh();
+    acosh();
+    atanh();
+    to_degrees();
+    to_radians();
+}
+
+impl<F: Float, C: FloatChecker<F>> Shadowed<NoisyFloat<F, C>> {
+    /// Computes `powi` on the value and on the shadow, reporting a loss of precision.
+    #[track_caller]
+    #[inline]
+    pub fn powi(self, n: i32) -> Self {
+        Self::compute(
+            self.raw().powi(n),
+            Float::powi(self.shadow, n),
+            "powi",
+            &[self.raw(), F::from(n).unwrap_or_else(F::nan)],
+            &[self],
+        )
+    }
+
+    /// Computes `sin_cos` on the value and on the shadow, reporting a loss of precision.
+    #[track_caller]
+    #[inline]
+    pub fn sin_cos(self) -> (Self, Self) {
+        (self.sin(), self.cos())
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> Neg for Shadowed<NoisyFloat<F, C>> {
+    type Output = Self;
+    #[track_caller]
+    #[inline]
+    fn neg(self) -> Self {
+        Shadowed {
+            value: -self.value,
+            shadow: -self.shadow,
+        }
+    }
+}
+
+impl<F: Float, C: FloatChecker<F>> Neg for &Shadowed<NoisyFloat<F, C>> {
+    type Output = Shadowed<NoisyFloat<F, C>>;
+    #[track_caller]
+    #[inline]
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+macro_rules! shadowed_op_impls {
+    ($trid:ident, $fnid:ident, $assign_trid:ident, $assign_fnid:ident, $symbol:literal) => {
+        op_impl!(
+            (F: Float, C: FloatChecker<F>), $trid,
+            (Shadowed<NoisyFloat<F, C>>, Shadowed<NoisyFloat<F, C>>),
+            $fnid(self, rhs: Shadowed<NoisyFloat<F, C>>) -> Shadowed<NoisyFloat<F, C>> {
+                let lhs: &Shadowed<NoisyFloat<F, C>> = &self;
+                let lhs = *lhs;
+                Shadowed::compute(
+                    lhs.raw().$fnid(rhs.raw()),
+                    lhs.shadow.$fnid(rhs.shadow),
+                    $symbol,
+                    &[lhs.raw(), rhs.raw()],
+                    &[lhs, rhs],
+                )
+            }
+        );
+        op_impl!(
+            (F: Float, C: FloatChecker<F>), $trid, (Shadowed<NoisyFloat<F, C>>, F),
+            $fnid(self, rhs: F) -> Shadowed<NoisyFloat<F, C>> {
+                let lhs: &Shadowed<NoisyFloat<F, C>> = &self;
+                let lhs = *lhs;
+                Shadowed::compute(
+                    lhs.raw().$fnid(rhs),
+                    lhs.shadow.$fnid(rhs.to_f64().unwrap_or(f64::NAN)),
+                    $symbol,
+                    &[lhs.raw(), rhs],
+                    &[lhs],
+                )
+            }
+        );
+
+        impl<F: Float, C: FloatChecker<F>> $assign_trid for Shadowed<NoisyFloat<F, C>> {
+            #[track_caller]
+            #[inline]
+            fn $assign_fnid(&mut self, rhs: Self) {
+                *self = $trid::$fnid(*self, rhs);
+            }
+        }
+
+        impl<F: Float, C: FloatChecker<F>> $assign_trid<F> for Shadowed<NoisyFloat<F, C>> {
+            #[track_caller]
+            #[inline]
+            fn $assign_fnid(&mut self, rhs: F) {
+                *self = $trid::$fnid(*self, rhs);
+            }
+        }
+    };
+}
+shadowed_op_impls!(Add, add, AddAssign, add_assign, "+");
+shadowed_op_impls!(Sub, sub, SubAssign, sub_assign, "-");
+shadowed_op_impls!(Mul, mul, MulAssign, mul_assign, "*");
+shadowed_op_impls!(Div, div, DivAssign, div_assign, "/");
+shadowed_op_impls!(Rem, rem, RemAssign, rem_assign, "%");
@@ -0,0 +1,584 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-call-site counters, enabled by the `profiling` feature.
+//!
+//! Every construction of a `NoisyFloat` through `new` or an operation,
+//! every check performed by the `assert` methods of the standard, `Strict`
+//! and `Sampled` checkers, and every violation they find is counted
+//! for its `#[track_caller]` location and checker name.
+//! `snapshot` returns the counters, starting with the call sites
+//! with the most violations.
+//!
+//! Values with a `RangeProfiler` checker also record the range of the values
+//! produced at each call site, returned by `ranges`,
+//! to tell which computations would fit in a narrower type such as `f32`.
+//!
+//! The counters of the first 256 pairs of call site and checker are atomics,
+//! so counting costs an atomic increment per event, without locking.
+//! Further pairs are counted in a map behind a `Mutex`, which is slower.
+//! `reset` zeroes the counters but keeps the pairs in their atomics.
+//!
+//! # Examples
+//! ```
+//! use noisy_float::{prelude::*, stats};
+//!
+//! let mut sum = strict_r64(0.0);
+//! for i in 0..100 {
+//!     sum += strict_r64(f64::from(i)) * 0.5;
+//! }
+//!
+//! // The construction and the multiplication start at the same column,
+//! // so they share a call site.
+//! let report = stats::snapshot();
+//! let hottest = &report[0];
+//! assert_eq!(hottest.checker(), "R64");
+//! assert_eq!(hottest.operations(), 100);
+//! assert_eq!(hottest.checks(), 200);
+//! ```
+
+use crate::FloatChecker;
+use core::{
+    cmp::Reverse,
+    fmt,
+    num::FpCategory,
+    panic::Location,
+    ptr,
+    sync::atomic::{AtomicPtr, AtomicU64, Ordering},
+};
+use num_traits::Float;
+use std::{
+    boxed::Box,
+    collections::BTreeMap,
+    sync::{Mutex, PoisonError},
+    vec::Vec,
+};
+
+type Key = (&'static str, u32, u32, &'static str);
+
+#[derive(Clone, Copy, Default, PartialEq)]
+struct Counters {
+    constructions: u64,
+    operations: u64,
+    checks: u64,
+    violations: u64,
+}
+
+impl Counters {
+    fn record(&mut self, event: Event) {
+        match event {
+            Event::Construction => self.constructions += 1,
+            Event::Operation => {
+                self.constructions += 1;
+                self.operations += 1;
+            }
+            Event::Check => self.checks += 1,
+            Event::Violation => self.violations += 1,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Range {
+    values: u64,
+    min_abs: f64,
+    max_abs: f64,
+    subnormals: u64,
+    zeros: u64,
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Range {
+            values: 0,
+            min_abs: f64::INFINITY,
+            max_abs: 0.0,
+            subnormals: 0,
+            zeros: 0,
+        }
+    }
+}
+
+impl Range {
+    fn record<F: Float>(&mut self, value: F) {
+        self.values += 1;
+        match value.classify() {
+            FpCategory::Nan => {}
+            FpCategory::Zero => self.zeros += 1,
+            category => {
+                if category == FpCategory::Subnormal {
+                    self.subnormals += 1;
+                }
+                let abs = value.abs().to_f64().unwrap_or(f64::NAN);
+                self.min_abs = self.min_abs.min(abs);
+                self.max_abs = self.max_abs.max(abs);
+            }
+        }
+    }
+}
+
+/// The number of pairs of call site and checker whose counters are atomics.
+const SLOTS: usize = 256;
+
+/// The counters and range of a pair of call site and checker, updated without locking.
+struct Slot {
+    key: AtomicPtr<Key>,
+    constructions: AtomicU64,
+    operations: AtomicU64,
+    checks: AtomicU64,
+    violations: AtomicU64,
+    values: AtomicU64,
+    // the bits of non-negative floats are ordered like the floats themselves
+    min_abs: AtomicU64,
+    max_abs: AtomicU64,
+    subnormals: AtomicU64,
+    zeros: AtomicU64,
+}
+
+impl Slot {
+    const fn new() -> Self {
+        Slot {
+            key: AtomicPtr::new(ptr::null_mut()),
+            constructions: AtomicU64::new(0),
+            operations: AtomicU64::new(0),
+            checks: AtomicU64::new(0),
+            violations: AtomicU64::new(0),
+            values: AtomicU64::new(0),
+            min_abs: AtomicU64::new(f64::INFINITY.to_bits()),
+            max_abs: AtomicU64::new(0),
+            subnormals: AtomicU64::new(0),
+            zeros: AtomicU64::new(0),
+        }
+    }
+
+    fn key(&self) -> Option<Key> {
+        let key = self.key.load(Ordering::Acquire);
+        // SAFETY: the only non-null values stored are leaked `Box<Key>` pointers.
+        (!key.is_null()).then(|| unsafe { *key })
+    }
+
+    fn record(&self, event: Event) {
+        let counter = match event {
+            Event::Construction => &self.constructions,
+            Event::Operation => {
+                self.constructions.fetch_add(1, Ordering::Relaxed);
+                &self.operations
+            }
+            Event::Check => &self.checks,
+            Event::Violation => &self.violations,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_range<F: Float>(&self, value: F) {
+        self.values.fetch_add(1, Ordering::Relaxed);
+        match value.classify() {
+            FpCategory::Nan => {}
+            FpCategory::Zero => {
+                self.zeros.fetch_add(1, Ordering::Relaxed);
+            }
+            category => {
+                if category == FpCategory::Subnormal {
+                    self.subnormals.fetch_add(1, Ordering::Relaxed);
+                }
+                if let Some(abs) = value.abs().to_f64() {
+                    self.min_abs.fetch_min(abs.to_bits(), Ordering::Relaxed);
+                    self.max_abs.fetch_max(abs.to_bits(), Ordering::Relaxed);
+                }
+            }
+        }
+    }
+
+    fn counters(&self) -> Counters {
+        Counters {
+            constructions: self.constructions.load(Ordering::Relaxed),
+            operations: self.operations.load(Ordering::Relaxed),
+            checks: self.checks.load(Ordering::Relaxed),
+            violations: self.violations.load(Ordering::Relaxed),
+        }
+    }
+
+    fn range(&self) -> Range {
+        Range {
+            values: self.values.load(Ordering::Relaxed),
+            min_abs: f64::from_bits(self.min_abs.load(Ordering::Relaxed)),
+            max_abs: f64::from_bits(self.max_abs.load(Ordering::Relaxed)),
+            subnormals: self.subnormals.load(Ordering::Relaxed),
+            zeros: self.zeros.load(Ordering::Relaxed),
+        }
+    }
+
+    fn reset(&self) {
+        for counter in [
+            &self.constructions,
+            &self.operations,
+            &self.checks,
+            &self.violations,
+            &self.values,
+            &self.max_abs,
+            &self.subnormals,
+            &self.zeros,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+        self.min_abs
+            .store(f64::INFINITY.to_bits(), Ordering::Relaxed);
+    }
+}
+
+static SLOT_TABLE: [Slot; SLOTS] = [const { Slot::new() }; SLOTS];
+
+// The pairs of call site and checker that found no free slot.
+static SITES: Mutex<BTreeMap<Key, Counters>> = Mutex::new(BTreeMap::new());
+
+static RANGES: Mutex<BTreeMap<Key, Range>> = Mutex::new(BTreeMap::new());
+
+/// The counters of a call site for one checker, as returned by `snapshot`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SiteStats {
+    file: &'static str,
+    line: u32,
+    column: u32,
+    checker: &'static str,
+    constructions: u64,
+    operations: u64,
+    checks: u64,
+    violations: u64,
+}
+
+impl SiteStats {
+    /// Returns the source file of the call site.
+    #[inline]
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Returns the line of the call site.
+    #[inline]
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the column of the call site.
+    #[inline]
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    /// Returns the name of the checker.
+    #[inline]
+    pub fn checker(&self) -> &'static str {
+        self.checker
+    }
+
+    /// Returns the number of values constructed, including those produced by operations.
+    #[inline]
+    pub fn constructions(&self) -> u64 {
+        self.constructions
+    }
+
+    /// Returns the number of values produced by operations, such as operators.
+    #[inline]
+    pub fn operations(&self) -> u64 {
+        self.operations
+    }
+
+    /// Returns the number of values that were actually checked.
+    #[inline]
+    pub fn checks(&self) -> u64 {
+        self.checks
+    }
+
+    /// Returns the number of *invalid* values found.
+    #[inline]
+    pub fn violations(&self) -> u64 {
+        self.violations
+    }
+}
+
+impl fmt::Display for SiteStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{}:{}:{} {}: {} constructions, {} operations, {} checks, {} violations",
+            self.file,
+            self.line,
+            self.column,
+            self.checker,
+            self.constructions,
+            self.operations,
+            self.checks,
+            self.violations
+        )
+    }
+}
+
+/// Returns the counters of all call sites,
+/// sorted by decreasing violations, then checks, then constructions.
+pub fn snapshot() -> Vec<SiteStats> {
+    let mut sites = SITES.lock().unwrap_or_else(PoisonError::into_inner).clone();
+    for slot in SLOT_TABLE.iter() {
+        if let Some(key) = slot.key() {
+            sites.insert(key, slot.counters());
+        }
+    }
+    let mut report: Vec<SiteStats> = sites
+        .iter()
+        .filter(|(_, counters)| **counters != Counters::default())
+        .map(|(&(file, line, column, checker), counters)| SiteStats {
+            file,
+            line,
+            column,
+            checker,
+            constructions: counters.constructions,
+            operations: counters.operations,
+            checks: counters.checks,
+            violations: counters.violations,
+        })
+        .collect();
+    report.sort_by(|a, b| {
+        (b.violations, b.checks, b.constructions).cmp(&(a.violations, a.checks, a.constructions))
+    });
+    report
+}
+
+/// The range of the values produced at a call site for one checker,
+/// as returned by `ranges`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SiteRange {
+    file: &'static str,
+    line: u32,
+    column: u32,
+    checker: &'static str,
+    range: Range,
+}
+
+impl SiteRange {
+    /// Returns the source file of the call site.
+    #[inline]
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Returns the line of the call site.
+    #[inline]
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the column of the call site.
+    #[inline]
+    pub fn column(&self) -> u32 {
+        self.column
+    }
+
+    /// Returns the name of the checker.
+    #[inline]
+    pub fn checker(&self) -> &'static str {
+        self.checker
+    }
+
+    /// Returns the number of values recorded.
+    #[inline]
+    pub fn values(&self) -> u64 {
+        self.range.values
+    }
+
+    /// Returns the smallest absolute value other than zero, converted to `f64`,
+    /// or `None` if all values were zero or NaN.
+    #[inline]
+    pub fn min_abs(&self) -> Option<f64> {
+        self.nonzero().then_some(self.range.min_abs)
+    }
+
+    /// Returns the largest absolute value other than zero, converted to `f64`,
+    /// or `None` if all values were zero or NaN.
+    #[inline]
+    pub fn max_abs(&self) -> Option<f64> {
+        self.nonzero().then_some(self.range.max_abs)
+    }
+
+    /// Returns the number of subnormal values, in the precision of the float type.
+    #[inline]
+    pub fn subnormals(&self) -> u64 {
+        self.range.subnormals
+    }
+
+    /// Returns the number of zeros, positive or negative.
+    #[inline]
+    pub fn zeros(&self) -> u64 {
+        self.range.zeros
+    }
+
+    /// Returns `true` if every value other than zero is within the range
+    /// of *normal* `f32` values, so that it could be stored as `f32`
+    /// with no more than the loss of precision.
+    #[inline]
+    pub fn fits_f32(&self) -> bool {
+        !self.nonzero()
+            || (self.range.min_abs >= f64::from(f32::MIN_POSITIVE)
+                && self.range.max_abs <= f64::from(f32::MAX))
+    }
+
+    fn nonzero(&self) -> bool {
+        self.range.min_abs <= self.range.max_abs
+    }
+}
+
+impl fmt::Display for SiteRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(
+            f,
+            "{}:{}:{} {}: {} values",
+            self.file, self.line, self.column, self.checker, self.range.values
+        )?;
+        if self.nonzero() {
+            write!(
+                f,
+                ", |x| in [{:e}, {:e}]",
+                self.range.min_abs, self.range.max_abs
+            )?;
+        }
+        write!(
+            f,
+            ", {} subnormals, {} zeros",
+            self.range.subnormals, self.range.zeros
+        )
+    }
+}
+
+/// Returns the ranges recorded by `RangeProfiler` checkers for all call sites,
+/// sorted by decreasing number of values.
+pub fn ranges() -> Vec<SiteRange> {
+    let mut ranges = RANGES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone();
+    for slot in SLOT_TABLE.iter() {
+        if let Some(key) = slot.key() {
+            ranges.insert(key, slot.range());
+        }
+    }
+    let mut report: Vec<SiteRange> = ranges
+        .iter()
+        .filter(|(_, range)| range.values > 0)
+        .map(|(&(file, line, column, checker), &range)| SiteRange {
+            file,
+            line,
+            column,
+            checker,
+            range,
+        })
+        .collect();
+    report.sort_by_key(|site| Reverse(site.range.values));
+    report
+}
+
+/// Resets all counters and ranges.
+pub fn reset() {
+    for slot in SLOT_TABLE.iter() {
+        slot.reset();
+    }
+    SITES.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    RANGES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clear();
+}
+
+pub(crate) enum Event {
+    Construction,
+    Operation,
+    Check,
+    Violation,
+}
+
+#[track_caller]
+fn key<F, C: FloatChecker<F>>() -> Key {
+    let location = Location::caller();
+    (
+        location.file(),
+        location.line(),
+        location.column(),
+        C::name(),
+    )
+}
+
+/// Returns the slot of the key, claiming a free one for its first event,
+/// or `None` if all slots are taken by other keys.
+fn slot(key: &Key) -> Option<&'static Slot> {
+    let start = (key.1 as usize)
+        .wrapping_mul(31)
+        .wrapping_add(key.2 as usize)
+        % SLOTS;
+    for i in 0..SLOTS {
+        let slot = &SLOT_TABLE[(start + i) % SLOTS];
+        let claimed = match slot.key() {
+            Some(claimed) => claimed,
+            None => {
+                let new = Box::into_raw(Box::new(*key));
+                match slot.key.compare_exchange(
+                    ptr::null_mut(),
+                    new,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                ) {
+                    Ok(_) => return Some(slot),
+                    Err(claimed) => {
+                        // SAFETY: `new` was never shared, since it was not stored,
+                        // and `claimed` is a leaked `Box<Key>` pointer.
+                        unsafe {
+                            drop(Box::from_raw(new));
+                            *claimed
+                        }
+                    }
+                }
+            }
+        };
+        if claimed == *key {
+            return Some(slot);
+        }
+    }
+    None
+}
+
+/// Counts the event for the checker `C` at the call site.
+#[track_caller]
+pub(crate) fn record<F: Float, C: FloatChecker<F>>(event: Event) {
+    let key = key::<F, C>();
+    match slot(&key) {
+        Some(slot) => slot.record(event),
+        None => SITES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_default()
+            .record(event),
+    }
+}
+
+/// Adds the value to the range of the checker `C` at the call site.
+#[track_caller]
+pub(crate) fn record_range<F: Float, C: FloatChecker<F>>(value: F) {
+    let key = key::<F, C>();
+    match slot(&key) {
+        Some(slot) => slot.record_range(value),
+        None => RANGES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key)
+            .or_default()
+            .record(value),
+    }
+}
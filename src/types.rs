@@ -16,13 +16,21 @@
 //!
 //! Definitions in this module all use `debug_assert!`
 //! to check for valid values, so there is no overhead
-//! when running in an optimized build.
+//! when running in an optimized build,
+//! except for the `Strict` types, which always use `assert!`,
+//! and the `Flagged` types, which raise sticky flags instead of panicking.
+//! With the `strict` feature, all of them except the `Flagged` types use `assert!`.
 
-use core::marker::PhantomData;
 use crate::{
-    checkers::{FiniteChecker, NumChecker},
+    checkers::{
+        FiniteChecker, Flagged, NegativeChecker, NegativeFiniteChecker, NonNegativeChecker,
+        NonNegativeFiniteChecker, NonPositiveChecker, NonPositiveFiniteChecker, NonZeroChecker,
+        NonZeroFiniteChecker, NormalChecker, NumChecker, PositiveChecker, PositiveFiniteChecker,
+        SignedUnitChecker, Strict, UnitChecker,
+    },
     NoisyFloat,
 };
+use core::marker::PhantomData;
 
 /// A floating point number behaving like `f32` that does not allow NaN.
 ///
@@ -48,6 +56,220 @@ pub type R32 = NoisyFloat<f32, FiniteChecker>;
 /// numbers do not include NaN or +/- Infinity.
 pub type R64 = NoisyFloat<f64, FiniteChecker>;
 
+/// A finite floating point number behaving like `f32` that must be greater than zero.
+///
+/// The "P" in the name stands for "Positive".
+pub type P32 = NoisyFloat<f32, PositiveFiniteChecker>;
+
+/// A finite floating point number behaving like `f64` that must be greater than zero.
+///
+/// The "P" in the name stands for "Positive".
+pub type P64 = NoisyFloat<f64, PositiveFiniteChecker>;
+
+/// A floating point number behaving like `f32` that must be greater than zero,
+/// allowing +Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+pub type PX32 = NoisyFloat<f32, PositiveChecker>;
+
+/// A floating point number behaving like `f64` that must be greater than zero,
+/// allowing +Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+pub type PX64 = NoisyFloat<f64, PositiveChecker>;
+
+/// A finite floating point number behaving like `f32` that must be greater than or equal to zero.
+///
+/// The "NN" in the name stands for "Non-Negative".
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NN32 = NoisyFloat<f32, NonNegativeFiniteChecker>;
+
+/// A finite floating point number behaving like `f64` that must be greater than or equal to zero.
+///
+/// The "NN" in the name stands for "Non-Negative".
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NN64 = NoisyFloat<f64, NonNegativeFiniteChecker>;
+
+/// A floating point number behaving like `f32` that must be greater than or equal to zero,
+/// allowing +Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NNX32 = NoisyFloat<f32, NonNegativeChecker>;
+
+/// A floating point number behaving like `f64` that must be greater than or equal to zero,
+/// allowing +Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NNX64 = NoisyFloat<f64, NonNegativeChecker>;
+
+/// A finite floating point number behaving like `f32` that must be less than zero.
+///
+/// The "NG" in the name stands for "NeGative".
+pub type NG32 = NoisyFloat<f32, NegativeFiniteChecker>;
+
+/// A finite floating point number behaving like `f64` that must be less than zero.
+///
+/// The "NG" in the name stands for "NeGative".
+pub type NG64 = NoisyFloat<f64, NegativeFiniteChecker>;
+
+/// A floating point number behaving like `f32` that must be less than zero,
+/// allowing -Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+pub type NGX32 = NoisyFloat<f32, NegativeChecker>;
+
+/// A floating point number behaving like `f64` that must be less than zero,
+/// allowing -Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+pub type NGX64 = NoisyFloat<f64, NegativeChecker>;
+
+/// A finite floating point number behaving like `f32` that must be less than or equal to zero.
+///
+/// The "NP" in the name stands for "Non-Positive".
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NP32 = NoisyFloat<f32, NonPositiveFiniteChecker>;
+
+/// A finite floating point number behaving like `f64` that must be less than or equal to zero.
+///
+/// The "NP" in the name stands for "Non-Positive".
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NP64 = NoisyFloat<f64, NonPositiveFiniteChecker>;
+
+/// A floating point number behaving like `f32` that must be less than or equal to zero,
+/// allowing -Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NPX32 = NoisyFloat<f32, NonPositiveChecker>;
+
+/// A floating point number behaving like `f64` that must be less than or equal to zero,
+/// allowing -Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+///
+/// Both +0.0 and -0.0 are valid values of this type.
+pub type NPX64 = NoisyFloat<f64, NonPositiveChecker>;
+
+/// A finite floating point number behaving like `f32` that must not be zero.
+///
+/// The "NZ" in the name stands for "Non-Zero".
+/// Dividing an `R32` by this type can not divide by zero.
+pub type NZ32 = NoisyFloat<f32, NonZeroFiniteChecker>;
+
+/// A finite floating point number behaving like `f64` that must not be zero.
+///
+/// The "NZ" in the name stands for "Non-Zero".
+/// Dividing an `R64` by this type can not divide by zero.
+pub type NZ64 = NoisyFloat<f64, NonZeroFiniteChecker>;
+
+/// A floating point number behaving like `f32` that must not be zero,
+/// allowing +/- Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+pub type NZX32 = NoisyFloat<f32, NonZeroChecker>;
+
+/// A floating point number behaving like `f64` that must not be zero,
+/// allowing +/- Infinity.
+///
+/// The "X" in the name stands for the "eXtended" real number line,
+/// which includes infinity.
+pub type NZX64 = NoisyFloat<f64, NonZeroChecker>;
+
+/// A floating point number behaving like `f32` that does not allow NaN, +/- Infinity
+/// or subnormal numbers.
+///
+/// The "NR" in the name stands for "Normal Real". Zero is allowed.
+pub type NR32 = NoisyFloat<f32, NormalChecker>;
+
+/// A floating point number behaving like `f64` that does not allow NaN, +/- Infinity
+/// or subnormal numbers.
+///
+/// The "NR" in the name stands for "Normal Real". Zero is allowed.
+pub type NR64 = NoisyFloat<f64, NormalChecker>;
+
+/// A floating point number behaving like `f32` that must lie in the closed interval `[0, 1]`.
+///
+/// The "U" in the name stands for "Unit interval".
+/// Multiplication, `complement`, `min`, `max` and `powf` with a non-negative exponent
+/// return a `U32` without checking, while the sum, difference or quotient of two `U32`
+/// is an `R32`. Since it is not closed under addition, this type does not implement `Float`.
+pub type U32 = NoisyFloat<f32, UnitChecker>;
+
+/// A floating point number behaving like `f64` that must lie in the closed interval `[0, 1]`.
+///
+/// The "U" in the name stands for "Unit interval".
+/// Multiplication, `complement`, `min`, `max` and `powf` with a non-negative exponent
+/// return a `U64` without checking, while the sum, difference or quotient of two `U64`
+/// is an `R64`. Since it is not closed under addition, this type does not implement `Float`.
+pub type U64 = NoisyFloat<f64, UnitChecker>;
+
+/// A floating point number behaving like `f32` that must lie in the closed interval `[-1, 1]`.
+///
+/// The "SU" in the name stands for "Signed Unit interval".
+/// This is the result type of `sin` and `cos` on `R32`.
+pub type SU32 = NoisyFloat<f32, SignedUnitChecker>;
+
+/// A floating point number behaving like `f64` that must lie in the closed interval `[-1, 1]`.
+///
+/// The "SU" in the name stands for "Signed Unit interval".
+/// This is the result type of `sin` and `cos` on `R64`.
+pub type SU64 = NoisyFloat<f64, SignedUnitChecker>;
+
+/// A floating point number behaving like `f32` that does not allow NaN,
+/// checked even in optimized builds.
+///
+/// Same as `N32`, but its checker uses `assert!` instead of `debug_assert!`.
+pub type StrictN32 = NoisyFloat<f32, Strict<NumChecker>>;
+
+/// A floating point number behaving like `f64` that does not allow NaN,
+/// checked even in optimized builds.
+///
+/// Same as `N64`, but its checker uses `assert!` instead of `debug_assert!`.
+pub type StrictN64 = NoisyFloat<f64, Strict<NumChecker>>;
+
+/// A floating point number behaving like `f32` that does not allow NaN or +/- Infinity,
+/// checked even in optimized builds.
+///
+/// Same as `R32`, but its checker uses `assert!` instead of `debug_assert!`.
+pub type StrictR32 = NoisyFloat<f32, Strict<FiniteChecker>>;
+
+/// A floating point number behaving like `f64` that does not allow NaN or +/- Infinity,
+/// checked even in optimized builds.
+///
+/// Same as `R64`, but its checker uses `assert!` instead of `debug_assert!`.
+pub type StrictR64 = NoisyFloat<f64, Strict<FiniteChecker>>;
+
+/// A floating point number behaving like `f32` that raises sticky flags
+/// instead of panicking on NaN or +/- Infinity.
+///
+/// See the `flags` module.
+pub type FlaggedR32 = NoisyFloat<f32, Flagged<FiniteChecker>>;
+
+/// A floating point number behaving like `f64` that raises sticky flags
+/// instead of panicking on NaN or +/- Infinity.
+///
+/// See the `flags` module.
+pub type FlaggedR64 = NoisyFloat<f64, Flagged<FiniteChecker>>;
+
 /// Shorthand for `N32::new(value)`.
 #[track_caller]
 #[inline]
@@ -76,6 +298,216 @@ pub fn r64(value: f64) -> R64 {
     R64::new(value)
 }
 
+/// Shorthand for `P32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn p32(value: f32) -> P32 {
+    P32::new(value)
+}
+
+/// Shorthand for `P64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn p64(value: f64) -> P64 {
+    P64::new(value)
+}
+
+/// Shorthand for `PX32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn px32(value: f32) -> PX32 {
+    PX32::new(value)
+}
+
+/// Shorthand for `PX64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn px64(value: f64) -> PX64 {
+    PX64::new(value)
+}
+
+/// Shorthand for `NN32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nn32(value: f32) -> NN32 {
+    NN32::new(value)
+}
+
+/// Shorthand for `NN64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nn64(value: f64) -> NN64 {
+    NN64::new(value)
+}
+
+/// Shorthand for `NNX32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nnx32(value: f32) -> NNX32 {
+    NNX32::new(value)
+}
+
+/// Shorthand for `NNX64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nnx64(value: f64) -> NNX64 {
+    NNX64::new(value)
+}
+
+/// Shorthand for `NG32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn ng32(value: f32) -> NG32 {
+    NG32::new(value)
+}
+
+/// Shorthand for `NG64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn ng64(value: f64) -> NG64 {
+    NG64::new(value)
+}
+
+/// Shorthand for `NGX32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn ngx32(value: f32) -> NGX32 {
+    NGX32::new(value)
+}
+
+/// Shorthand for `NGX64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn ngx64(value: f64) -> NGX64 {
+    NGX64::new(value)
+}
+
+/// Shorthand for `NP32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn np32(value: f32) -> NP32 {
+    NP32::new(value)
+}
+
+/// Shorthand for `NP64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn np64(value: f64) -> NP64 {
+    NP64::new(value)
+}
+
+/// Shorthand for `NPX32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn npx32(value: f32) -> NPX32 {
+    NPX32::new(value)
+}
+
+/// Shorthand for `NPX64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn npx64(value: f64) -> NPX64 {
+    NPX64::new(value)
+}
+
+/// Shorthand for `NZ32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nz32(value: f32) -> NZ32 {
+    NZ32::new(value)
+}
+
+/// Shorthand for `NZ64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nz64(value: f64) -> NZ64 {
+    NZ64::new(value)
+}
+
+/// Shorthand for `NZX32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nzx32(value: f32) -> NZX32 {
+    NZX32::new(value)
+}
+
+/// Shorthand for `NZX64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nzx64(value: f64) -> NZX64 {
+    NZX64::new(value)
+}
+
+/// Shorthand for `NR32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nr32(value: f32) -> NR32 {
+    NR32::new(value)
+}
+
+/// Shorthand for `NR64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn nr64(value: f64) -> NR64 {
+    NR64::new(value)
+}
+
+/// Shorthand for `SU32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn su32(value: f32) -> SU32 {
+    SU32::new(value)
+}
+
+/// Shorthand for `SU64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn su64(value: f64) -> SU64 {
+    SU64::new(value)
+}
+
+/// Shorthand for `StrictN32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn strict_n32(value: f32) -> StrictN32 {
+    StrictN32::new(value)
+}
+
+/// Shorthand for `StrictN64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn strict_n64(value: f64) -> StrictN64 {
+    StrictN64::new(value)
+}
+
+/// Shorthand for `StrictR32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn strict_r32(value: f32) -> StrictR32 {
+    StrictR32::new(value)
+}
+
+/// Shorthand for `StrictR64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn strict_r64(value: f64) -> StrictR64 {
+    StrictR64::new(value)
+}
+
+/// Shorthand for `U32::new(value)`.
+#[track_caller]
+#[inline]
+pub fn unit32(value: f32) -> U32 {
+    U32::new(value)
+}
+
+/// Shorthand for `U64::new(value)`.
+#[track_caller]
+#[inline]
+pub fn unit64(value: f64) -> U64 {
+    U64::new(value)
+}
+
 macro_rules! const_fns {
     ($type:ty, $raw:ty) => {
         impl $type {
@@ -104,3 +536,35 @@ const_fns!(N32, f32);
 const_fns!(N64, f64);
 const_fns!(R32, f32);
 const_fns!(R64, f64);
+const_fns!(P32, f32);
+const_fns!(P64, f64);
+const_fns!(PX32, f32);
+const_fns!(PX64, f64);
+const_fns!(NN32, f32);
+const_fns!(NN64, f64);
+const_fns!(NNX32, f32);
+const_fns!(NNX64, f64);
+const_fns!(NG32, f32);
+const_fns!(NG64, f64);
+const_fns!(NGX32, f32);
+const_fns!(NGX64, f64);
+const_fns!(NP32, f32);
+const_fns!(NP64, f64);
+const_fns!(NPX32, f32);
+const_fns!(NPX64, f64);
+const_fns!(NZ32, f32);
+const_fns!(NZ64, f64);
+const_fns!(NZX32, f32);
+const_fns!(NZX64, f64);
+const_fns!(NR32, f32);
+const_fns!(NR64, f64);
+const_fns!(U32, f32);
+const_fns!(U64, f64);
+const_fns!(SU32, f32);
+const_fns!(SU64, f64);
+const_fns!(StrictN32, f32);
+const_fns!(StrictN64, f64);
+const_fns!(StrictR32, f32);
+const_fns!(StrictR64, f64);
+const_fns!(FlaggedR32, f32);
+const_fns!(FlaggedR64, f64);
@@ -0,0 +1,425 @@
+// Copyright 2016-2021 Matthew D. Michelotti
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A global handler for *invalid* values.
+//!
+//! When a standard checker rejects a value, it calls `report`,
+//! or `report_operation` if the value is the result of an operation,
+//! which passes a `Violation` to the handler registered with `set_handler`.
+//! The handler can log the violation or count it,
+//! and decides whether to panic, to continue with the invalid value,
+//! or to continue with a replacement value.
+//! Without a handler, invalid values panic.
+//!
+//! The handler is a `fn` pointer stored in an atomic, so this works with no_std.
+//!
+//! With the `log` or `tracing` features, handlers are provided that emit an event
+//! for each violation, with the operation, operands, checker and location,
+//! and then either continue or panic.
+//! Note that the standard checkers only check values in optimized builds
+//! with the `strict` feature, while the `Strict` types always do.
+//!
+//! # Examples
+//! ```
+//! use core::sync::atomic::{AtomicUsize, Ordering};
+//! use noisy_float::{
+//!     checkers::{FiniteChecker, Strict},
+//!     violation::{self, Action, Violation},
+//!     NoisyFloat,
+//! };
+//!
+//! static NAN_EVENTS: AtomicUsize = AtomicUsize::new(0);
+//!
+//! fn record(violation: &Violation) -> Action {
+//!     if violation.value().is_nan() {
+//!         NAN_EVENTS.fetch_add(1, Ordering::Relaxed);
+//!         Action::Replace(0.0)
+//!     } else {
+//!         Action::Panic
+//!     }
+//! }
+//!
+//! violation::set_handler(record);
+//! let x = NoisyFloat::<f64, Strict<FiniteChecker>>::new(0.0);
+//! let y = x / x;
+//! assert_eq!(y.raw(), 0.0);
+//! assert_eq!(NAN_EVENTS.load(Ordering::Relaxed), 1);
+//! violation::reset_handler();
+//! ```
+
+use crate::{FloatChecker, Operation};
+use core::{
+    fmt, mem,
+    panic::Location,
+    ptr,
+    sync::atomic::{AtomicPtr, Ordering},
+};
+use num_traits::Float;
+
+/// An *invalid* value rejected by a checker, as passed to the violation handler.
+#[derive(Clone, Copy, Debug)]
+pub struct Violation {
+    value: f64,
+    checker: &'static str,
+    message: &'static str,
+    operation: Option<Operation<f64>>,
+    shadow: Option<f64>,
+    location: &'static Location<'static>,
+}
+
+impl Violation {
+    /// Constructs a `Violation` of a value not produced by a known operation,
+    /// located at the caller, such as to test a handler.
+    #[track_caller]
+    #[inline]
+    pub fn new(value: f64, checker: &'static str, message: &'static str) -> Self {
+        Violation {
+            value,
+            checker,
+            message,
+            operation: None,
+            shadow: None,
+            location: Location::caller(),
+        }
+    }
+
+    /// Constructs the `Violation` of a value rejected by the checker `C`,
+    /// located at the caller.
+    #[track_caller]
+    #[inline]
+    pub(crate) fn capture<F: Float, C: FloatChecker<F>>(
+        value: F,
+        operation: Option<Operation<F>>,
+    ) -> Self {
+        let to_f64 = |value: F| value.to_f64().unwrap_or(f64::NAN);
+        Violation {
+            value: to_f64(value),
+            checker: C::name(),
+            message: C::message(value),
+            operation: operation.map(|operation| operation.map(to_f64)),
+            shadow: None,
+            location: Location::caller(),
+        }
+    }
+
+    /// Returns the rejected value, converted to `f64`.
+    ///
+    /// The conversion is exact for `f32` and `f64`.
+    #[inline]
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Returns the name of the checker that rejected the value.
+    #[inline]
+    pub fn checker(&self) -> &'static str {
+        self.checker
+    }
+
+    /// Returns the message of the checker describing why the value was rejected.
+    #[inline]
+    pub fn message(&self) -> &'static str {
+        self.message
+    }
+
+    /// Returns the operation that produced the value, if it is known,
+    /// with its operands converted to `f64`.
+    #[inline]
+    pub fn operation(&self) -> Option<&Operation<f64>> {
+        self.operation.as_ref()
+    }
+
+    /// Returns the value computed in `f64` alongside the rejected value
+    /// by a `Shadowed` type, if the violation is a loss of accuracy.
+    #[inline]
+    pub fn shadow(&self) -> Option<f64> {
+        self.shadow
+    }
+
+    /// Returns the location in the source code where the value was created.
+    #[inline]
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(f, "{}: {} ", self.message, self.value)?;
+        if let Some(shadow) = self.shadow {
+            write!(f, "instead of {} ", shadow)?;
+        }
+        if let Some(operation) = &self.operation {
+            write!(f, "produced by {} ", operation)?;
+        }
+        write!(f, "rejected by {} at {}", self.checker, self.location)
+    }
+}
+
+/// What to do after the violation handler has been called.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Action {
+    /// Panic with the message of the checker.
+    Panic,
+    /// Continue with the *invalid* value.
+    ///
+    /// The `NoisyFloat` then holds a value that its checker considers *invalid*,
+    /// as it would in an optimized build without checks.
+    Continue,
+    /// Continue with the given value instead, converted to the float type.
+    ///
+    /// The replacement is not checked, so it should be *valid* for the checker.
+    /// Values passed to `NoisyFloat::borrowed` cannot be replaced,
+    /// and continue as with `Action::Continue`.
+    Replace(f64),
+}
+
+/// A violation handler, called with each *invalid* value that is reported.
+pub type Handler = fn(&Violation) -> Action;
+
+static HANDLER: AtomicPtr<()> = AtomicPtr::new(ptr::null_mut());
+
+/// Registers the violation handler, replacing the previous one.
+///
+/// # Release builds
+///
+/// The standard checkers, such as those of `R64` or `P64`, do not check values
+/// in release builds unless the `strict` feature is enabled,
+/// so the handler is never called for them in a release build without `strict`.
+/// The `Strict` types always check their values and call the handler in every build,
+/// and so do `Shadowed` values when they lose precision.
+#[inline]
+pub fn set_handler(handler: Handler) {
+    HANDLER.store(handler as *mut (), Ordering::Release);
+}
+
+/// Removes the violation handler, so that *invalid* values panic again.
+#[inline]
+pub fn reset_handler() {
+    HANDLER.store(ptr::null_mut(), Ordering::Release);
+}
+
+/// Returns the registered violation handler, if any.
+#[inline]
+pub fn handler() -> Option<Handler> {
+    let handler = HANDLER.load(Ordering::Acquire);
+    if handler.is_null() {
+        None
+    } else {
+        // SAFETY: the only non-null values stored are `Handler` pointers cast by `set_handler`.
+        // A `fn` pointer cast to a data pointer and back is the same `fn` pointer,
+        // since both have the same size on every target supported by Rust,
+        // which `transmute` checks at compile time.
+        Some(unsafe { mem::transmute::<*mut (), Handler>(handler) })
+    }
+}
+
+/// A handler that logs each violation as an error with the `log` crate,
+/// and continues with the *invalid* value.
+///
+/// The record has the key-value pairs `value`, `checker`, `operation`, `operands`,
+/// `shadow`, `file`, `line` and `column`, and the message of the checker.
+#[cfg(feature = "log")]
+pub fn log_and_continue(violation: &Violation) -> Action {
+    log_violation(violation);
+    Action::Continue
+}
+
+/// A handler that logs each violation as an error with the `log` crate,
+/// and then panics.
+///
+/// The record has the same key-value pairs as for `log_and_continue`.
+#[cfg(feature = "log")]
+pub fn log_and_panic(violation: &Violation) -> Action {
+    log_violation(violation);
+    Action::Panic
+}
+
+/// Logs the violation as an error with the `log` crate,
+/// for use in custom handlers, e.g. ones that return `Action::Replace`.
+#[cfg(feature = "log")]
+pub fn log_violation(violation: &Violation) {
+    let operation = violation.operation();
+    log::error!(
+        target: "noisy_float",
+        value = violation.value,
+        checker = violation.checker,
+        operation = operation.map(|operation| operation.name()),
+        operands:? = operation.map(|operation| operation.operands()),
+        shadow = violation.shadow,
+        file = violation.location.file(),
+        line = violation.location.line(),
+        column = violation.location.column();
+        "{}",
+        violation.message,
+    );
+}
+
+/// A handler that emits each violation as an error event with the `tracing` crate,
+/// and continues with the *invalid* value.
+///
+/// The event has the fields `value`, `checker`, `operation`, `operands`,
+/// `shadow`, `file`, `line` and `column`, and the message of the checker.
+#[cfg(feature = "tracing")]
+pub fn trace_and_continue(violation: &Violation) -> Action {
+    trace_violation(violation);
+    Action::Continue
+}
+
+/// A handler that emits each violation as an error event with the `tracing` crate,
+/// and then panics.
+///
+/// The event has the same fields as for `trace_and_continue`.
+#[cfg(feature = "tracing")]
+pub fn trace_and_panic(violation: &Violation) -> Action {
+    trace_violation(violation);
+    Action::Panic
+}
+
+/// Emits the violation as an error event with the `tracing` crate,
+/// with the same fields as for `trace_and_continue`,
+/// for use in custom handlers, e.g. ones that return `Action::Replace`.
+#[cfg(feature = "tracing")]
+pub fn trace_violation(violation: &Violation) {
+    let operation = violation.operation();
+    tracing::error!(
+        target: "noisy_float",
+        value = violation.value,
+        checker = violation.checker,
+        operation = operation.map(|operation| operation.name()),
+        operands = ?operation.map(|operation| operation.operands()),
+        shadow = violation.shadow,
+        file = violation.location.file(),
+        line = violation.location.line(),
+        column = violation.location.column(),
+        "{}",
+        violation.message,
+    );
+}
+
+/// Displays a float in its own precision, since `Float` does not require `Display`.
+#[derive(Clone, Copy)]
+struct Number<F>(F);
+
+impl<F: Float> fmt::Display for Number<F> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.0.to_f32() {
+            Some(value) if mem::size_of::<F>() <= mem::size_of::<f32>() => {
+                fmt::Display::fmt(&value, f)
+            }
+            _ => fmt::Display::fmt(&self.0.to_f64().unwrap_or(f64::NAN), f),
+        }
+    }
+}
+
+/// Reports a value rejected by the checker `C` to the violation handler,
+/// and returns the value to continue with, unless the handler returns `Action::Panic`.
+///
+/// This is called by the `assert` method of the standard checkers,
+/// and can be called by custom checkers too.
+/// The location is that of the caller, which is tracked through `#[track_caller]`.
+/// The panic message is the message of the checker.
+#[track_caller]
+#[inline]
+pub fn report<F: Float, C: FloatChecker<F>>(value: F) -> F {
+    report_violation::<F, C>(value, None)
+}
+
+/// Reports a value produced by an operation and rejected by the checker `C`
+/// to the violation handler, and returns the value to continue with,
+/// unless the handler returns `Action::Panic`.
+///
+/// This is called by the `assert_operation` method of the standard checkers.
+/// The panic message names the checker, the operation and the value,
+/// such as `"R64: 0 / 0 produced NaN"`.
+#[track_caller]
+#[inline]
+pub fn report_operation<F: Float, C: FloatChecker<F>>(value: F, operation: Operation<F>) -> F {
+    report_violation::<F, C>(value, Some(operation))
+}
+
+#[cold]
+#[track_caller]
+fn report_violation<F: Float, C: FloatChecker<F>>(value: F, operation: Option<Operation<F>>) -> F {
+    if let Some(value) = resolve(&Violation::capture::<F, C>(value, operation), value) {
+        return value;
+    }
+    match operation {
+        Some(operation) => panic!(
+            "{}: {} produced {}",
+            C::name(),
+            operation.map(Number),
+            Number(value)
+        ),
+        None => panic!("{}", C::message(value)),
+    }
+}
+
+/// Returns the value to continue with after passing the violation to the handler,
+/// or `None` to panic.
+fn resolve<F: Float>(violation: &Violation, value: F) -> Option<F> {
+    match handler().map_or(Action::Panic, |handler| handler(violation)) {
+        Action::Panic => None,
+        Action::Continue => Some(value),
+        Action::Replace(replacement) => F::from(replacement),
+    }
+}
+
+/// Reports a value produced by an operation on `Shadowed` values,
+/// whose relative error to the `f64` shadow exceeds the tolerance,
+/// to the violation handler, and returns the value to continue with,
+/// unless the handler returns `Action::Panic`.
+#[cold]
+#[track_caller]
+pub(crate) fn report_inaccuracy<F: Float, C: FloatChecker<F>>(
+    value: F,
+    shadow: f64,
+    operation: Operation<F>,
+) -> F {
+    let mut violation = Violation::capture::<F, C>(value, Some(operation));
+    violation.message = "relative error exceeds the tolerance";
+    violation.shadow = Some(shadow);
+    match resolve(&violation, value) {
+        Some(value) => value,
+        None => panic!(
+            "{}: {} produced {} instead of {}",
+            C::name(),
+            operation.map(Number),
+            Number(value),
+            shadow
+        ),
+    }
+}
+
+/// Reports the result of an addition or subtraction that cancelled
+/// too many significant bits of its operands to the violation handler,
+/// and returns the value to continue with, unless the handler returns `Action::Panic`.
+#[cold]
+#[track_caller]
+pub(crate) fn report_cancellation<F: Float, C: FloatChecker<F>>(
+    value: F,
+    operation: Operation<F>,
+) -> F {
+    let mut violation = Violation::capture::<F, C>(value, Some(operation));
+    violation.message = "catastrophic cancellation";
+    match resolve(&violation, value) {
+        Some(value) => value,
+        None => panic!(
+            "{}: {} produced {}, a catastrophic cancellation",
+            C::name(),
+            operation.map(Number),
+            Number(value)
+        ),
+    }
+}